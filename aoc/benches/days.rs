@@ -0,0 +1,144 @@
+// Benchmarks parsing and both parts of every day on the real inputs, plus the alternative
+// strategies some days have. Run with `cargo bench`, or e.g. `cargo bench -- day_06` for one day.
+use std::{env, fs, hint::black_box, path::Path, time::Duration};
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, SamplingMode};
+
+// Set to anything to also benchmark the parts that take minutes, which takes hours
+const SLOW_PARTS_VAR: &str = "AOC_BENCH_SLOW";
+
+fn read_input(day: u8) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{day}/input.txt"));
+
+    fs::read_to_string(path).ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Parts {
+    Both,
+    // Part two takes minutes, so it is only run with `AOC_BENCH_SLOW`, and as few times as
+    // criterion allows
+    SlowPartTwo,
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, parts: Parts) {
+    let Some(input) = read_input(day) else {
+        eprintln!("no input for day {day}, skipping");
+        return;
+    };
+
+    let data = S::parse(&input, S::Params::default())
+        .unwrap_or_else(|err| panic!("could not parse the input of day {day}: {err}"));
+
+    let mut group = c.benchmark_group(format!("day_{day:02}"));
+
+    group.bench_function("parse", |b| {
+        b.iter(|| S::parse(black_box(&input), S::Params::default()))
+    });
+    group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&data))));
+
+    match parts {
+        Parts::Both => {}
+        Parts::SlowPartTwo if env::var_os(SLOW_PARTS_VAR).is_some() => {
+            group
+                .sampling_mode(SamplingMode::Flat)
+                .warm_up_time(Duration::from_millis(1))
+                .measurement_time(Duration::from_secs(1));
+        }
+        Parts::SlowPartTwo => {
+            eprintln!(
+                "skipping part two of day {day}, it takes minutes: set {SLOW_PARTS_VAR}=1 to run it"
+            );
+            group.finish();
+            return;
+        }
+    }
+
+    group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&data))));
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_1::Day>(c, 1, Parts::Both);
+    bench_day::<day_2::Day>(c, 2, Parts::Both);
+    bench_day::<day_3::Day>(c, 3, Parts::Both);
+    bench_day::<day_4::Day>(c, 4, Parts::Both);
+    bench_day::<day_5::Day>(c, 5, Parts::Both);
+    bench_day::<day_6::Day>(c, 6, Parts::Both);
+    bench_day::<day_7::Day>(c, 7, Parts::Both);
+    bench_day::<day_8::Day>(c, 8, Parts::Both);
+    bench_day::<day_9::Day>(c, 9, Parts::Both);
+    bench_day::<day_10::Day>(c, 10, Parts::Both);
+    bench_day::<day_11::Day>(c, 11, Parts::Both);
+    bench_day::<day_12::Day>(c, 12, Parts::Both);
+    bench_day::<day_13::Day>(c, 13, Parts::Both);
+    bench_day::<day_14::Day>(c, 14, Parts::Both);
+    bench_day::<day_15::Day>(c, 15, Parts::Both);
+    // Part two searches the k shortest paths with an ever growing k
+    bench_day::<day_16::Day>(c, 16, Parts::SlowPartTwo);
+    bench_day::<day_17::Day>(c, 17, Parts::Both);
+    bench_day::<day_18::Day>(c, 18, Parts::Both);
+    bench_day::<day_19::Day>(c, 19, Parts::Both);
+    bench_day::<day_20::Day>(c, 20, Parts::Both);
+    bench_day::<day_21::Day>(c, 21, Parts::Both);
+    // Part two tries all 19^4 change sequences on every buyer
+    bench_day::<day_22::Day>(c, 22, Parts::SlowPartTwo);
+    bench_day::<day_23::Day>(c, 23, Parts::Both);
+    bench_day::<day_24::Day>(c, 24, Parts::Both);
+    bench_day::<day_25::Day>(c, 25, Parts::Both);
+}
+
+fn day_6_strategies(c: &mut Criterion) {
+    let Some(input) = read_input(6) else {
+        return;
+    };
+    let map = day_6::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group("day_06_part_two");
+
+    group.bench_function("simple_bruteforce", |b| {
+        b.iter(|| day_6::part_two_simple_bruteforce(black_box(&map)))
+    });
+    group.bench_function("smart_bruteforce", |b| {
+        b.iter(|| day_6::part_two_smart_bruteforce(black_box(&map)))
+    });
+    group.bench_function("smart", |b| {
+        b.iter(|| day_6::part_two_smart(black_box(&map)))
+    });
+
+    group.finish();
+}
+
+fn day_11_strategies(c: &mut Criterion) {
+    let Some(input) = read_input(11) else {
+        return;
+    };
+    let data = day_11::parse(&input, day_11::Params::default()).unwrap();
+
+    let mut group = c.benchmark_group("day_11_blink");
+
+    // blink_raw keeps every single stone around, so it can't go much further than part one
+    for blinks in [10, 25] {
+        group.bench_with_input(BenchmarkId::new("raw", blinks), &blinks, |b, blinks| {
+            b.iter(|| day_11::blink_raw(black_box(&data), *blinks))
+        });
+        group.bench_with_input(BenchmarkId::new("dynamic", blinks), &blinks, |b, blinks| {
+            b.iter(|| day_11::blink_dynamic(black_box(&data), *blinks))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // Several parts take the better part of a second, and criterion's default of 100 samples
+    // would keep those busy for minutes each
+    config = Criterion::default().sample_size(10);
+    targets = days, day_6_strategies, day_11_strategies
+}
+criterion_main!(benches);
@@ -0,0 +1,110 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    answers::{AnswerStore, Verdict},
+    input::InputSource,
+    memory,
+    params::ParamsSource,
+    run::{run_day, PartRun},
+    Part,
+};
+
+// The outcome of a single part of a single day when running everything
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    // None if the input could not be read, or the day panicked
+    pub parse_time: Option<Duration>,
+    pub result: Result<PartRun, String>,
+}
+
+impl Row {
+    // None without a store, or if the part failed or has no answer
+    pub fn check(&self, answers: Option<&AnswerStore>) -> Option<Verdict> {
+        let part_run = self.result.as_ref().ok()?;
+        answers?.check(self.day, self.part, &part_run.answer)
+    }
+}
+
+// Runs the selected parts of all 25 days at once, each part as its own task on rayon's thread pool.
+// Every task parses its input again, which is quick compared to solving it. A day that fails
+// (no input, a parse error or a panic) only fails its own rows.
+pub fn run_all(source: &InputSource, part: Part) -> Vec<Row> {
+    let tasks: Vec<(u8, u8)> = (1..=25)
+        .flat_map(|day| part.numbers().map(move |part| (day, part)))
+        .collect();
+
+    // Counted allocations can only be told apart if the parts run one after another
+    let mut rows: Vec<Row> = if memory::COUNTING {
+        tasks
+            .into_iter()
+            .map(|(day, part)| run_part(source, day, part))
+            .collect()
+    } else {
+        tasks
+            .into_par_iter()
+            .map(|(day, part)| run_part(source, day, part))
+            .collect()
+    };
+
+    rows.sort_by_key(|row| (row.day, row.part));
+
+    rows
+}
+
+fn run_part(source: &InputSource, day: u8, part: u8) -> Row {
+    let failed = |parse_time, error| Row {
+        day,
+        part,
+        parse_time,
+        result: Err(error),
+    };
+
+    let input = match source.read(day) {
+        Ok(input) => input,
+        Err(err) => return failed(None, err.to_string()),
+    };
+
+    // Only the TOML files next to the inputs apply here, as the parameters differ per day
+    let params = match ParamsSource::default().load(source, day) {
+        Ok(params) => params,
+        Err(err) => return failed(None, err.to_string()),
+    };
+
+    let only_this_part = if part == 1 { Part::One } else { Part::Two };
+
+    let run = match panic::catch_unwind(AssertUnwindSafe(|| {
+        run_day(day, only_this_part, &input, params, |_| {})
+    })) {
+        Ok(run) => run,
+        Err(payload) => return failed(None, format!("panicked: {}", panic_message(&*payload))),
+    };
+
+    match run.result {
+        Ok(mut parts) => Row {
+            day,
+            part,
+            parse_time: Some(run.parse_time),
+            result: Ok(parts.remove(0)),
+        },
+        Err(err) => failed(Some(run.parse_time), err.describe(source, day)),
+    }
+}
+
+// Panics carry whatever was passed to panic!, which is almost always a string
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown reason"
+    }
+}
@@ -0,0 +1,57 @@
+use render::{Animate, Animation};
+
+use crate::{
+    run::{deserialize_params, DayError},
+    Part,
+};
+
+pub const ANIMATED_DAYS: [u8; 6] = [6, 8, 12, 14, 15, 18];
+
+type AnimateFn = fn(Part, &str, toml::Table, usize) -> Result<Animation, DayError>;
+
+// Records the simulation of the selected parts of a day, keeping at most `limit` frames.
+// None if the day has nothing to animate.
+pub fn animate_day(
+    day: u8,
+    part: Part,
+    input: &str,
+    params: toml::Table,
+    limit: usize,
+) -> Option<Result<Animation, DayError>> {
+    let animate: AnimateFn = match day {
+        6 => animate::<day_6::Day>,
+        8 => animate::<day_8::Day>,
+        12 => animate::<day_12::Day>,
+        14 => animate::<day_14::Day>,
+        15 => animate::<day_15::Day>,
+        18 => animate::<day_18::Day>,
+        _ => return None,
+    };
+
+    Some(animate(part, input, params, limit))
+}
+
+fn animate<S: Animate>(
+    part: Part,
+    input: &str,
+    params: toml::Table,
+    limit: usize,
+) -> Result<Animation, DayError> {
+    let params = deserialize_params::<S>(params)?;
+    let data = S::parse(input, params).map_err(DayError::Parse)?;
+
+    let mut animation = Animation::with_limit(limit);
+
+    for number in part.numbers() {
+        S::animate(&data, number, &mut animation);
+    }
+
+    Ok(animation)
+}
+
+pub fn parse_fps(arg: &str) -> Result<f64, String> {
+    match arg.parse::<f64>() {
+        Ok(fps) if fps > 0.0 && fps.is_finite() => Ok(fps),
+        _ => Err(format!("`{arg}` is not a positive number")),
+    }
+}
@@ -0,0 +1,463 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use colored::{ColoredString, Colorize};
+use common::Answer;
+use serde::{Deserialize, Serialize};
+
+// The answers Advent of Code accepted or rejected for your own input, kept in a TOML file:
+//
+//   [day_07.part_1]
+//   correct = "3749"
+//   wrong = ["3750"]
+//   too_low = ["1234"]
+//
+// Every run of a personal input is checked against it. Examples and other files have
+// different answers, so they are never checked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    days: BTreeMap<String, BTreeMap<String, PartAnswers>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    correct: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    wrong: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    too_high: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    too_low: Vec<String>,
+}
+
+impl PartAnswers {
+    fn rejected(&mut self, rejection: Rejection) -> &mut Vec<String> {
+        match rejection {
+            Rejection::Wrong => &mut self.wrong,
+            Rejection::TooHigh => &mut self.too_high,
+            Rejection::TooLow => &mut self.too_low,
+        }
+    }
+
+    fn rejection_of(&self, answer: &str) -> Option<Rejection> {
+        [
+            (Rejection::Wrong, &self.wrong),
+            (Rejection::TooHigh, &self.too_high),
+            (Rejection::TooLow, &self.too_low),
+        ]
+        .into_iter()
+        .find(|(_, rejected)| rejected.iter().any(|rejected| rejected == answer))
+        .map(|(rejection, _)| rejection)
+    }
+}
+
+// What Advent of Code said about an answer it did not accept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Rejection {
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::Wrong => write!(f, "rejected"),
+            Rejection::TooHigh => write!(f, "rejected as too high"),
+            Rejection::TooLow => write!(f, "rejected as too low"),
+        }
+    }
+}
+
+// How an answer compares to what is known about the part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    // Not the accepted answer
+    Wrong { correct: String },
+    // This very answer was rejected before
+    Rejected(Rejection),
+    // Never submitted, but at least as high as an answer that was too high
+    TooHigh { than: String },
+    // Never submitted, but at most as low as an answer that was too low
+    TooLow { than: String },
+    Unknown,
+}
+
+impl Verdict {
+    // "correct", "wrong" or "unknown", for `--format json`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Unknown => "unknown",
+            _ => "wrong",
+        }
+    }
+
+    // Why a wrong answer is too high or too low, if that is known
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Rejected(Rejection::TooHigh) | Verdict::TooHigh { .. } => Some("too_high"),
+            Verdict::Rejected(Rejection::TooLow) | Verdict::TooLow { .. } => Some("too_low"),
+            _ => None,
+        }
+    }
+
+    pub fn colored(&self) -> ColoredString {
+        match self {
+            Verdict::Correct => self.to_string().green(),
+            Verdict::Unknown => self.to_string().dimmed(),
+            _ => self.to_string().red().bold(),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { correct } => write!(f, "wrong, the accepted answer is {correct}"),
+            Verdict::Rejected(rejection) => write!(f, "wrong, already {rejection}"),
+            Verdict::TooHigh { than } => write!(f, "too high, {than} already was"),
+            Verdict::TooLow { than } => write!(f, "too low, {than} already was"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl AnswerStore {
+    // A store that doesn't exist yet is None, it is only created by saving an answer
+    pub fn load(path: &Path) -> Result<Option<Self>, AnswersError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(source) => {
+                return Err(AnswersError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        toml::from_str(&text)
+            .map(Some)
+            .map_err(|source| AnswersError::Toml {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let text = toml::to_string(self).expect("the store only contains strings");
+
+        fs::write(path, text).map_err(|source| AnswersError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    // None for parts that have no answer to check, like day 25 part two
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Option<Verdict> {
+        if *answer == Answer::None {
+            return None;
+        }
+
+        let Some(known) = self.part(day, part) else {
+            return Some(Verdict::Unknown);
+        };
+
+        let text = answer.to_string();
+
+        let verdict = match (&known.correct, known.rejection_of(&text)) {
+            (Some(correct), _) if *correct == text => Verdict::Correct,
+            (Some(correct), _) => Verdict::Wrong {
+                correct: correct.clone(),
+            },
+            (None, Some(rejection)) => Verdict::Rejected(rejection),
+            (None, None) => match answer {
+                Answer::Number(n) => out_of_range(known, *n),
+                _ => Verdict::Unknown,
+            },
+        };
+
+        Some(verdict)
+    }
+
+    pub fn confirm(&mut self, day: u8, part: u8, answer: &Answer) -> Result<(), String> {
+        let answer = answer.to_string();
+        let known = self.part_mut(day, part);
+
+        if let Some(rejection) = known.rejection_of(&answer) {
+            return Err(format!("{answer} was already {rejection}"));
+        }
+
+        known.correct = Some(answer);
+
+        Ok(())
+    }
+
+    pub fn reject(
+        &mut self,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+        rejection: Rejection,
+    ) -> Result<(), String> {
+        let answer = answer.to_string();
+        let known = self.part_mut(day, part);
+
+        if known.correct.as_ref() == Some(&answer) {
+            return Err(format!("{answer} is the accepted answer"));
+        }
+
+        let rejected = known.rejected(rejection);
+        if !rejected.contains(&answer) {
+            rejected.push(answer);
+        }
+
+        Ok(())
+    }
+
+    fn part(&self, day: u8, part: u8) -> Option<&PartAnswers> {
+        self.days.get(&day_key(day))?.get(&part_key(part))
+    }
+
+    fn part_mut(&mut self, day: u8, part: u8) -> &mut PartAnswers {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(part_key(part))
+            .or_default()
+    }
+}
+
+// Zero padded, so the days are in order in the file
+fn day_key(day: u8) -> String {
+    format!("day_{day:02}")
+}
+
+fn part_key(part: u8) -> String {
+    format!("part_{part}")
+}
+
+// Uses the hints to rule out answers that were never submitted
+fn out_of_range(known: &PartAnswers, answer: i128) -> Verdict {
+    let numbers = |answers: &[String]| {
+        answers
+            .iter()
+            .filter_map(|answer| answer.parse::<i128>().ok())
+            .collect::<Vec<_>>()
+    };
+
+    if let Some(than) = numbers(&known.too_high)
+        .into_iter()
+        .filter(|too_high| answer >= *too_high)
+        .min()
+    {
+        return Verdict::TooHigh {
+            than: than.to_string(),
+        };
+    }
+
+    if let Some(than) = numbers(&known.too_low)
+        .into_iter()
+        .filter(|too_low| answer <= *too_low)
+        .max()
+    {
+        return Verdict::TooLow {
+            than: than.to_string(),
+        };
+    }
+
+    Verdict::Unknown
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    Write {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Read { path, source } => {
+                write!(
+                    f,
+                    "could not read answers file {}: {source}",
+                    path.display()
+                )
+            }
+            AnswersError::Toml { path, source } => {
+                write!(
+                    f,
+                    "could not parse answers file {}: {source}",
+                    path.display()
+                )
+            }
+            AnswersError::Write { path, source } => {
+                write!(
+                    f,
+                    "could not write answers file {}: {source}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Read { source, .. } => Some(source),
+            AnswersError::Toml { source, .. } => Some(source),
+            AnswersError::Write { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Answer;
+
+    use super::{AnswerStore, Rejection, Verdict};
+
+    fn number(n: i128) -> Answer {
+        Answer::Number(n)
+    }
+
+    #[test]
+    fn test_correct_wrong_unknown() {
+        let mut answers = AnswerStore::default();
+
+        assert_eq!(answers.check(7, 1, &number(3749)), Some(Verdict::Unknown));
+        assert_eq!(answers.check(7, 1, &Answer::None), None);
+
+        answers.confirm(7, 1, &number(3749)).unwrap();
+
+        assert_eq!(answers.check(7, 1, &number(3749)), Some(Verdict::Correct));
+        assert_eq!(
+            answers.check(7, 1, &number(3750)),
+            Some(Verdict::Wrong {
+                correct: "3749".to_string()
+            })
+        );
+        // Other parts and days know nothing yet
+        assert_eq!(answers.check(7, 2, &number(3749)), Some(Verdict::Unknown));
+        assert_eq!(answers.check(8, 1, &number(3749)), Some(Verdict::Unknown));
+
+        assert_eq!(Verdict::Correct.name(), "correct");
+        assert_eq!(Verdict::Unknown.name(), "unknown");
+        assert_eq!(Verdict::Rejected(Rejection::Wrong).name(), "wrong");
+    }
+
+    #[test]
+    fn test_rejected() {
+        let mut answers = AnswerStore::default();
+
+        answers
+            .reject(24, 2, &"a,b".into(), Rejection::Wrong)
+            .unwrap();
+
+        assert_eq!(
+            answers.check(24, 2, &"a,b".into()),
+            Some(Verdict::Rejected(Rejection::Wrong))
+        );
+        // Text can't be too high or too low
+        assert_eq!(answers.check(24, 2, &"a,c".into()), Some(Verdict::Unknown));
+
+        assert!(answers.confirm(24, 2, &"a,b".into()).is_err());
+
+        answers.confirm(24, 2, &"a,c".into()).unwrap();
+        assert!(answers
+            .reject(24, 2, &"a,c".into(), Rejection::Wrong)
+            .is_err());
+    }
+
+    #[test]
+    fn test_inferred_hints() {
+        let mut answers = AnswerStore::default();
+
+        answers
+            .reject(1, 1, &number(100), Rejection::TooHigh)
+            .unwrap();
+        answers
+            .reject(1, 1, &number(120), Rejection::TooHigh)
+            .unwrap();
+        answers
+            .reject(1, 1, &number(50), Rejection::TooLow)
+            .unwrap();
+
+        let check = |n| answers.check(1, 1, &number(n)).unwrap();
+
+        assert_eq!(check(100), Verdict::Rejected(Rejection::TooHigh));
+        assert_eq!(check(50), Verdict::Rejected(Rejection::TooLow));
+
+        // The lowest answer that was too high and is still below it decides
+        assert_eq!(
+            check(130),
+            Verdict::TooHigh {
+                than: "100".to_string()
+            }
+        );
+        assert_eq!(
+            check(101),
+            Verdict::TooHigh {
+                than: "100".to_string()
+            }
+        );
+        assert_eq!(
+            check(49),
+            Verdict::TooLow {
+                than: "50".to_string()
+            }
+        );
+
+        // Between the two hints nothing is known
+        assert_eq!(check(51), Verdict::Unknown);
+        assert_eq!(check(99), Verdict::Unknown);
+
+        assert_eq!(check(101).hint(), Some("too_high"));
+        assert_eq!(check(49).hint(), Some("too_low"));
+        assert_eq!(check(51).hint(), None);
+    }
+
+    #[test]
+    fn test_toml() {
+        let mut answers = AnswerStore::default();
+
+        answers.confirm(7, 1, &number(3749)).unwrap();
+        answers
+            .reject(7, 2, &number(10), Rejection::TooLow)
+            .unwrap();
+        answers
+            .reject(7, 2, &number(10), Rejection::TooLow)
+            .unwrap();
+
+        let text = toml::to_string(&answers).unwrap();
+
+        assert_eq!(
+            text,
+            "[day_07.part_1]\ncorrect = \"3749\"\n\n[day_07.part_2]\ntoo_low = [\"10\"]\n"
+        );
+        assert_eq!(toml::from_str::<AnswerStore>(&text).unwrap(), answers);
+    }
+}
@@ -0,0 +1,38 @@
+use std::io;
+
+use render::Explore;
+
+use crate::run::{deserialize_params, DayError};
+
+pub const EXPLORABLE_DAYS: [u8; 6] = [6, 8, 10, 12, 18, 20];
+
+type ExploreFn = fn(&str, toml::Table) -> Result<io::Result<()>, DayError>;
+
+// Lets the map of a day be explored in the terminal until q is pressed. None if the day has
+// no map to explore.
+pub fn explore_day(
+    day: u8,
+    input: &str,
+    params: toml::Table,
+) -> Option<Result<io::Result<()>, DayError>> {
+    let explore: ExploreFn = match day {
+        6 => explore::<day_6::Day>,
+        8 => explore::<day_8::Day>,
+        10 => explore::<day_10::Day>,
+        12 => explore::<day_12::Day>,
+        18 => explore::<day_18::Day>,
+        20 => explore::<day_20::Day>,
+        _ => return None,
+    };
+
+    Some(explore(input, params))
+}
+
+fn explore<S: Explore>(input: &str, params: toml::Table) -> Result<io::Result<()>, DayError> {
+    let params = deserialize_params::<S>(params)?;
+    let data = S::parse(input, params).map_err(DayError::Parse)?;
+
+    let exploration = S::explore(&data);
+
+    Ok(render::explore(&exploration))
+}
@@ -0,0 +1,32 @@
+// A random input of roughly the given size for a day, see the `generate` of each day for what
+// the size means there. The same seed always gives the same input.
+pub fn generate_input(day: u8, size: usize, seed: u64) -> String {
+    match day {
+        1 => day_1::generate(size, seed),
+        2 => day_2::generate(size, seed),
+        3 => day_3::generate(size, seed),
+        4 => day_4::generate(size, seed),
+        5 => day_5::generate(size, seed),
+        6 => day_6::generate(size, seed),
+        7 => day_7::generate(size, seed),
+        8 => day_8::generate(size, seed),
+        9 => day_9::generate(size, seed),
+        10 => day_10::generate(size, seed),
+        11 => day_11::generate(size, seed),
+        12 => day_12::generate(size, seed),
+        13 => day_13::generate(size, seed),
+        14 => day_14::generate(size, seed),
+        15 => day_15::generate(size, seed),
+        16 => day_16::generate(size, seed),
+        17 => day_17::generate(size, seed),
+        18 => day_18::generate(size, seed),
+        19 => day_19::generate(size, seed),
+        20 => day_20::generate(size, seed),
+        21 => day_21::generate(size, seed),
+        22 => day_22::generate(size, seed),
+        23 => day_23::generate(size, seed),
+        24 => day_24::generate(size, seed),
+        25 => day_25::generate(size, seed),
+        _ => unreachable!("there are only days 1 to 25"),
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+// Where the puzzle input for a day comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // A single file, given explicitly
+    File(PathBuf),
+    Stdin,
+    // A directory with one file per day, named like `day_07.txt`
+    InputsDir(PathBuf),
+    // The `input.txt` inside the day's own crate folder
+    DayFolder,
+}
+
+// The folder of a day's crate. It is found from where this crate was built, so the binary can
+// run from any directory.
+pub fn day_folder(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{day}"))
+}
+
+impl InputSource {
+    pub fn path_for_day(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+            InputSource::InputsDir(dir) => Some(dir.join(format!("day_{day:02}.txt"))),
+            InputSource::DayFolder => Some(day_folder(day).join("input.txt")),
+        }
+    }
+
+    // Whether this is the input of whoever runs it, which is what the answer store is about
+    pub fn is_personal(&self) -> bool {
+        matches!(self, InputSource::InputsDir(_) | InputSource::DayFolder)
+    }
+
+    // How to refer to the input in messages
+    pub fn describe(&self, day: u8) -> String {
+        match self.path_for_day(day) {
+            Some(path) => path.display().to_string(),
+            None => "input from stdin".to_string(),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match self.path_for_day(day) {
+            Some(path) => read_file(&path),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+
+    // For days that read their input a line at a time, see `run::stream_day`
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        match self.path_for_day(day) {
+            Some(path) => match File::open(&path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(source) => Err(InputError::File { path, source }),
+            },
+            None => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+// The input of a day, all at once or as a reader for `--stream`
+pub enum Input {
+    Text(String),
+    Reader(Box<dyn BufRead>),
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    let mut input = String::new();
+
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut input))
+        .map_err(|source| InputError::File {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    Ok(input)
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    File { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::File { path, source } => {
+                write!(f, "could not read input file {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "could not read input from stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::File { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
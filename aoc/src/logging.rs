@@ -0,0 +1,158 @@
+use std::env;
+
+use log::{LevelFilter, Log, Metadata, Record};
+use simple_logger::SimpleLogger;
+
+// Nothing is logged unless RUST_LOG asks for it. It takes a comma separated list like env_logger:
+// `module=level` sets the level of one module, a bare `module` turns all of its output on, and
+// a bare `level` applies to all other modules. So `RUST_LOG=day_17=debug` shows how far the brute
+// force of day 17 got, and `RUST_LOG=info,day_24=trace` also every swap that day 24 tries.
+pub fn init() -> Result<(), String> {
+    let filter = match env::var("RUST_LOG") {
+        Ok(spec) => Filter::parse(&spec)?,
+        Err(_) => Filter::default(),
+    };
+
+    log::set_max_level(filter.max_level());
+    log::set_boxed_logger(Box::new(FilteredLogger {
+        filter,
+        inner: SimpleLogger::new(),
+    }))
+    .map_err(|err| err.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+    default: LevelFilter,
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            default: LevelFilter::Off,
+            modules: Vec::new(),
+        }
+    }
+}
+
+impl Filter {
+    fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Filter::default();
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let parse_level = |level: &str| {
+                level
+                    .trim()
+                    .parse::<LevelFilter>()
+                    .map_err(|_| format!("invalid log level `{level}` in RUST_LOG"))
+            };
+
+            match directive.split_once('=') {
+                Some((module, level)) => filter
+                    .modules
+                    .push((module.trim().to_string(), parse_level(level)?)),
+                None => match directive.parse::<LevelFilter>() {
+                    Ok(level) => filter.default = level,
+                    Err(_) => filter
+                        .modules
+                        .push((directive.to_string(), LevelFilter::Trace)),
+                },
+            }
+        }
+
+        // The most specific module decides, and for the same module the last one given
+        filter
+            .modules
+            .sort_by_key(|(module, _)| module.split("::").count());
+        filter.modules.reverse();
+
+        Ok(filter)
+    }
+
+    fn level(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .find(|(module, _)| {
+                // `day_1` covers `day_1::generate`, but not `day_10`
+                target == module
+                    || target
+                        .strip_prefix(module.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+// simple_logger does the formatting, but matches modules by prefix only
+struct FilteredLogger {
+    filter: Filter,
+    inner: SimpleLogger,
+}
+
+impl Log for FilteredLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use log::LevelFilter;
+
+    use super::Filter;
+
+    #[test]
+    fn test_default_and_module() {
+        let filter = Filter::parse("warn,day_17=debug").unwrap();
+
+        assert_eq!(filter.level("day_17"), LevelFilter::Debug);
+        assert_eq!(filter.level("day_17::generate"), LevelFilter::Debug);
+        assert_eq!(filter.level("day_1"), LevelFilter::Warn);
+        assert_eq!(filter.level("day_170"), LevelFilter::Warn);
+        assert_eq!(filter.max_level(), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn test_module_prefixes() {
+        let filter = Filter::parse("day_1").unwrap();
+
+        assert_eq!(filter.level("day_1"), LevelFilter::Trace);
+        assert_eq!(filter.level("day_1::generate"), LevelFilter::Trace);
+        assert_eq!(filter.level("day_17"), LevelFilter::Off);
+        assert_eq!(filter.level("day_10::generate"), LevelFilter::Off);
+    }
+
+    #[test]
+    fn test_precedence() {
+        let filter =
+            Filter::parse("day_2::generate=off, day_2=trace, day_5=info, day_5=error").unwrap();
+
+        // The most specific module wins, wherever it is in the list
+        assert_eq!(filter.level("day_2"), LevelFilter::Trace);
+        assert_eq!(filter.level("day_2::generate"), LevelFilter::Off);
+
+        // For the same module the last one given
+        assert_eq!(filter.level("day_5"), LevelFilter::Error);
+
+        assert_eq!(Filter::parse("").unwrap(), Filter::default());
+        assert!(Filter::parse("day_3=loud").is_err());
+    }
+}
@@ -0,0 +1,497 @@
+mod all;
+mod animate;
+mod answers;
+mod explore;
+mod generate;
+mod input;
+mod logging;
+mod memory;
+mod params;
+mod report;
+mod run;
+
+use std::{
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use answers::{AnswerStore, AnswersError, Rejection};
+use clap::{ArgGroup, Parser, ValueEnum};
+use common::Answer;
+use input::{Input, InputSource};
+use memory::MemoryUsage;
+use params::ParamsSource;
+use render::Player;
+use report::Record;
+use run::{run_day, stream_day, PartRun, STREAMING_DAYS};
+
+#[derive(Debug, Parser)]
+#[command(about = "Run the Advent of Code 2024 solutions")]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+#[command(group(ArgGroup::new("simulation").args(["animate", "export"])))]
+struct Args {
+    /// The day to run
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Run all days at once and print a summary table
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    /// Which part of the day to run
+    #[arg(long, value_enum, default_value_t = Part::All)]
+    part: Part,
+
+    /// Read the input from this file instead ("-" reads from stdin)
+    #[arg(long, value_name = "PATH", conflicts_with = "inputs_dir")]
+    input: Option<PathBuf>,
+
+    /// Read the input from a directory containing day_01.txt to day_25.txt
+    #[arg(long, value_name = "DIR")]
+    inputs_dir: Option<PathBuf>,
+
+    /// Read the input a line at a time instead of into one string first (only day 1). The IDs
+    /// of the lists are still all kept in memory. Both parts are found in the same pass, so
+    /// only the pass as a whole is timed
+    #[arg(long, conflicts_with_all = ["all", "generate", "simulation", "explore"])]
+    stream: bool,
+
+    /// Read the day's parameters from this TOML file. By default the TOML file next to the
+    /// input with the same name is used if there is one, like day_14/example.toml for
+    /// day_14/example.txt, or day_14/input.toml for the day's own input.txt
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    params: Option<PathBuf>,
+
+    /// Override a single parameter of the day, e.g. --param width=11 (can be repeated)
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override, conflicts_with = "all")]
+    param_overrides: Vec<(String, toml::Value)>,
+
+    /// Print a random input for the day instead of solving it. What the size means depends on
+    /// the day, e.g. the number of lines or the width of the map
+    #[arg(long, value_name = "SIZE", conflicts_with_all = ["all", "input", "inputs_dir"])]
+    generate: Option<usize>,
+
+    /// The seed for --generate, the same seed always gives the same input
+    #[arg(long, default_value_t = 0, requires = "generate")]
+    seed: u64,
+
+    /// How many pairs of gate outputs --generate swaps in the adder of day 24 (4 like the
+    /// puzzle if not given)
+    #[arg(long, value_name = "COUNT", requires = "generate")]
+    swaps: Option<usize>,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Play the simulation of the day in the terminal instead of printing the answers
+    /// (days 6, 8, 12, 14, 15 and 18). While it plays, type a command and press Enter: nothing to
+    /// step, b to step back, p to pause, g N to go to frame N, + or - to change the speed and q
+    /// to quit
+    #[arg(long, conflicts_with_all = ["all", "generate", "format"])]
+    animate: bool,
+
+    /// Save the simulation of the day instead of printing the answers: all frames as an
+    /// animated GIF if PATH ends in .gif, otherwise the last frame as PNG or PPM
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["all", "generate", "format"])]
+    export: Option<PathBuf>,
+
+    /// How many pixels wide and high every tile of the map is in an exported image
+    #[arg(long, value_name = "PIXELS", default_value_t = 4, requires = "export")]
+    scale: usize,
+
+    /// Frames per second of the animation
+    #[arg(long, default_value_t = 10.0, value_parser = animate::parse_fps, requires = "simulation")]
+    fps: f64,
+
+    /// Only keep the last N frames of the animation, all frames of a long simulation on a big
+    /// map take a lot of memory. The frames before those are dropped, so the start of a long
+    /// simulation can't be watched or exported, the frame numbers still count them
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 2000,
+        requires = "simulation"
+    )]
+    frames: usize,
+
+    /// Start the animation paused on its first frame
+    #[arg(long, requires = "animate")]
+    paused: bool,
+
+    /// Look around the map of the day in the terminal instead of printing the answers (days 6, 8,
+    /// 10, 12, 18 and 20): move with the arrow keys or h/j/k/l to see what there is to know
+    /// about a tile, step through the simulation with n and b and switch overlays like the
+    /// path found on and off with 1 to 9. q quits
+    #[arg(long, conflicts_with_all = ["all", "generate", "format", "simulation"])]
+    explore: bool,
+
+    /// The TOML file with the answers Advent of Code accepted or rejected. Every run of your own
+    /// input (so not with --input) is checked against it
+    #[arg(long, value_name = "PATH", default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Save the answer of the part (pick one with --part) as the accepted one
+    #[arg(long, conflicts_with_all = ["all", "input", "generate", "simulation", "explore", "reject"])]
+    confirm: bool,
+
+    /// Save the answer of the part (pick one with --part) as rejected, with the hint Advent of
+    /// Code gave for it
+    #[arg(long, value_enum, value_name = "HINT", conflicts_with_all = ["all", "input", "generate", "simulation", "explore"])]
+    reject: Option<Rejection>,
+}
+
+impl Args {
+    fn input_source(&self) -> InputSource {
+        match (&self.input, &self.inputs_dir) {
+            (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(path.clone()),
+            (None, Some(dir)) => InputSource::InputsDir(dir.clone()),
+            (None, None) => InputSource::DayFolder,
+        }
+    }
+
+    fn params_source(&self) -> ParamsSource {
+        ParamsSource {
+            file: self.params.clone(),
+            overrides: self.param_overrides.clone(),
+        }
+    }
+
+    // Other inputs have other answers, so only your own are checked
+    fn load_answers(&self, source: &InputSource) -> Result<Option<AnswerStore>, AnswersError> {
+        if source.is_personal() {
+            AnswerStore::load(&self.answers)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn saves_answer(&self) -> bool {
+        self.confirm || self.reject.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    All,
+}
+
+impl Part {
+    fn includes_one(self) -> bool {
+        matches!(self, Part::One | Part::All)
+    }
+
+    fn includes_two(self) -> bool {
+        matches!(self, Part::Two | Part::All)
+    }
+
+    fn numbers(self) -> impl Iterator<Item = u8> {
+        [(1, self.includes_one()), (2, self.includes_two())]
+            .into_iter()
+            .filter_map(|(number, included)| included.then_some(number))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    // "Part one: 42 (1.2ms)"
+    Text,
+    // One JSON object per line and part, see `report::Record`
+    Json,
+}
+
+fn main() -> ExitCode {
+    if let Err(err) = logging::init() {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    let args = Args::parse();
+
+    let source = args.input_source();
+
+    if args.all {
+        return run_everything(&args, &source);
+    }
+
+    let day = args.day.expect("clap requires either --day or --all");
+
+    if let Some(size) = args.generate {
+        let input = match args.swaps {
+            Some(swaps) if day == 24 => day_24::generate_with_swaps(size, swaps, args.seed),
+            Some(_) => {
+                eprintln!("error: --swaps is only for the adder of day 24");
+                return ExitCode::FAILURE;
+            }
+            None => generate::generate_input(day, size, args.seed),
+        };
+        print!("{input}");
+        return ExitCode::SUCCESS;
+    }
+
+    if args.explore && source == InputSource::Stdin {
+        eprintln!("error: --explore reads the keys from stdin, so the input can't come from there");
+        return ExitCode::FAILURE;
+    }
+
+    if args.stream && !STREAMING_DAYS.contains(&day) {
+        eprintln!(
+            "error: day {day} reads its input all at once, only days {} can stream it",
+            STREAMING_DAYS.map(|day| day.to_string()).join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    if args.saves_answer() && args.part == Part::All {
+        eprintln!("error: --confirm and --reject save the answer of one part, pick it with --part");
+        return ExitCode::FAILURE;
+    }
+
+    let input = if args.stream {
+        source.open(day).map(Input::Reader)
+    } else {
+        source.read(day).map(Input::Text)
+    };
+
+    let loaded = input.map_err(|err| err.to_string()).and_then(|input| {
+        let params = args
+            .params_source()
+            .load(&source, day)
+            .map_err(|err| err.to_string())?;
+        let answers = args.load_answers(&source).map_err(|err| err.to_string())?;
+        Ok((input, params, answers))
+    });
+
+    let (input, params, answers) = match loaded {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            match args.format {
+                Format::Text => eprintln!("error: {err}"),
+                Format::Json => {
+                    for part in args.part.numbers() {
+                        Record::failed(day, part, None, err.clone()).print();
+                    }
+                }
+            }
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let check = |part_run: &PartRun| {
+        answers
+            .as_ref()
+            .and_then(|answers| answers.check(day, part_run.part, &part_run.answer))
+    };
+
+    let print_part = |part_run: &PartRun| {
+        if args.format == Format::Text {
+            let name = if part_run.part == 1 { "one" } else { "two" };
+            let line = match measurements(part_run.time, part_run.memory) {
+                Some(measured) => format!("Part {name}: {} ({measured})", part_run.answer),
+                None => format!("Part {name}: {}", part_run.answer),
+            };
+            match check(part_run) {
+                Some(verdict) => println!("{line}, {}", verdict.colored()),
+                None => println!("{line}"),
+            }
+        }
+    };
+
+    let run = match input {
+        Input::Text(input) => {
+            if args.animate || args.export.is_some() {
+                return show_simulation(&args, &source, day, &input, params);
+            }
+
+            if args.explore {
+                return explore_map(&source, day, &input, params);
+            }
+
+            run_day(day, args.part, &input, params, print_part)
+        }
+        Input::Reader(reader) => stream_day(day, args.part, reader, params, print_part),
+    };
+
+    match (&run.result, args.format) {
+        (Ok(_), Format::Text) if args.stream => {
+            if let Some(measured) = measurements(Some(run.parse_time), run.parse_memory) {
+                println!("Both parts in one pass over the input: {measured}");
+            }
+        }
+        (Ok(_), Format::Text) => {}
+        (Ok(parts), Format::Json) => {
+            for part_run in parts {
+                Record::solved(run.day, run.parse_time, part_run)
+                    .with_verdict(check(part_run).as_ref())
+                    .print();
+            }
+        }
+        (Err(err), Format::Text) => eprintln!("error: {}", err.describe(&source, day)),
+        (Err(err), Format::Json) => {
+            let error = err.describe(&source, day);
+            for part in args.part.numbers() {
+                Record::failed(run.day, part, Some(run.parse_time), error.clone()).print();
+            }
+        }
+    }
+
+    let Ok(parts) = &run.result else {
+        return ExitCode::FAILURE;
+    };
+
+    if args.saves_answer() {
+        if let Err(err) = save_answer(&args, day, &parts[0], answers) {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Like "1.2ms, peak 3 KiB, 5 KiB in 7 allocations", None if nothing was measured
+fn measurements(time: Option<Duration>, memory: Option<MemoryUsage>) -> Option<String> {
+    match (time, memory) {
+        (Some(time), Some(memory)) => Some(format!("{time:?}, {memory}")),
+        (Some(time), None) => Some(format!("{time:?}")),
+        (None, Some(memory)) => Some(memory.to_string()),
+        (None, None) => None,
+    }
+}
+
+// Stores what Advent of Code said about the answer of a part, for `--confirm` and `--reject`
+fn save_answer(
+    args: &Args,
+    day: u8,
+    part_run: &PartRun,
+    answers: Option<AnswerStore>,
+) -> Result<(), String> {
+    let mut answers = answers.unwrap_or_default();
+    let (answer, part) = (&part_run.answer, part_run.part);
+
+    if *answer == Answer::None {
+        return Err(format!("day {day} part {part} has no answer to save"));
+    }
+
+    let note = match args.reject {
+        Some(rejection) => {
+            answers.reject(day, part, answer, rejection)?;
+            format!("that {answer} was {rejection} for day {day} part {part}")
+        }
+        None => {
+            answers.confirm(day, part, answer)?;
+            format!("{answer} as the answer to day {day} part {part}")
+        }
+    };
+
+    answers.save(&args.answers).map_err(|err| err.to_string())?;
+
+    eprintln!("Saved {note} in {}", args.answers.display());
+
+    Ok(())
+}
+
+fn show_simulation(
+    args: &Args,
+    source: &InputSource,
+    day: u8,
+    input: &str,
+    params: toml::Table,
+) -> ExitCode {
+    let animation = match animate::animate_day(day, args.part, input, params, args.frames) {
+        Some(Ok(animation)) => animation,
+        Some(Err(err)) => {
+            eprintln!("error: {}", err.describe(source, day));
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!(
+                "error: day {day} has no animation, only days {} do",
+                animate::ANIMATED_DAYS.map(|day| day.to_string()).join(", ")
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match &args.export {
+        Some(path) => animation
+            .save(path, args.scale, args.fps)
+            .map_err(|err| format!("could not save {}: {err}", path.display())),
+        None => Player::new(args.fps)
+            .paused(args.paused)
+            .play(&animation)
+            .map_err(|err| format!("could not play the animation: {err}")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn explore_map(source: &InputSource, day: u8, input: &str, params: toml::Table) -> ExitCode {
+    let result = match explore::explore_day(day, input, params) {
+        Some(Ok(result)) => result,
+        Some(Err(err)) => {
+            eprintln!("error: {}", err.describe(source, day));
+            return ExitCode::FAILURE;
+        }
+        None => {
+            eprintln!(
+                "error: day {day} has no map to explore, only days {} do",
+                explore::EXPLORABLE_DAYS
+                    .map(|day| day.to_string())
+                    .join(", ")
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: could not explore the map: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_everything(args: &Args, source: &InputSource) -> ExitCode {
+    let answers = match args.load_answers(source) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let time = Instant::now();
+    let rows = all::run_all(source, args.part);
+    let wall_time = time.elapsed();
+
+    match args.format {
+        Format::Text => report::print_table(&rows, wall_time, answers.as_ref()),
+        Format::Json => {
+            for row in &rows {
+                Record::from(row)
+                    .with_verdict(row.check(answers.as_ref()).as_ref())
+                    .print();
+            }
+        }
+    }
+
+    if rows.iter().all(|row| row.result.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
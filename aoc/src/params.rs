@@ -0,0 +1,251 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::input::InputSource;
+
+// Where the parameters of a day come from, lowest priority first: the defaults of the day itself,
+// then a TOML file, then `--param name=value` on the command line.
+// Without `--params`, the TOML file next to the input with the same name is used if there is
+// one, so `--input day_14/example.txt` picks up `day_14/example.toml`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParamsSource {
+    pub file: Option<PathBuf>,
+    pub overrides: Vec<(String, toml::Value)>,
+}
+
+impl ParamsSource {
+    pub fn load(&self, input: &InputSource, day: u8) -> Result<toml::Table, ParamsError> {
+        let mut params = match (&self.file, default_path(input, day)) {
+            (Some(path), _) => read_table(path)?,
+            (None, Some(path)) if path.exists() => read_table(&path)?,
+            (None, _) => toml::Table::new(),
+        };
+
+        for (name, value) in &self.overrides {
+            insert_override(&mut params, name, value.clone());
+        }
+
+        Ok(params)
+    }
+}
+
+// A dotted name like `rules.max_step` sets a value in a table of the parameters, keeping what
+// else is in there
+fn insert_override(params: &mut toml::Table, name: &str, value: toml::Value) {
+    let Some((table, rest)) = name.split_once('.') else {
+        params.insert(name.to_string(), value);
+        return;
+    };
+
+    let entry = params
+        .entry(table)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+
+    // Whatever was there is replaced, like any other value that is overridden
+    if !entry.is_table() {
+        *entry = toml::Value::Table(toml::Table::new());
+    }
+
+    if let toml::Value::Table(table) = entry {
+        insert_override(table, rest, value);
+    }
+}
+
+// The input file with a .toml extension instead, input from stdin has none
+fn default_path(input: &InputSource, day: u8) -> Option<PathBuf> {
+    input
+        .path_for_day(day)
+        .map(|path| path.with_extension("toml"))
+}
+
+fn read_table(path: &Path) -> Result<toml::Table, ParamsError> {
+    let text = fs::read_to_string(path).map_err(|source| ParamsError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    text.parse().map_err(|source| ParamsError::Toml {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+// Parses `--param name=value`. The value is read as TOML, so `size=7` is a number and
+// `name=true` a boolean, and anything that isn't valid TOML is taken as a plain string. The
+// name may be dotted, like `rules.max_step=5`, to set a value in a table.
+pub fn parse_override(arg: &str) -> Result<(String, toml::Value), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got `{arg}`"))?;
+
+    let parts: Vec<&str> = name.split('.').map(str::trim).collect();
+    if parts.iter().any(|part| part.is_empty()) {
+        return Err(format!("missing parameter name in `{arg}`"));
+    }
+    let name = parts.join(".");
+
+    let value = format!("value = {value}")
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_string()));
+
+    Ok((name, value))
+}
+
+#[derive(Debug)]
+pub enum ParamsError {
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+impl Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamsError::Read { path, source } => {
+                write!(f, "could not read params file {}: {source}", path.display())
+            }
+            ParamsError::Toml { path, source } => {
+                write!(
+                    f,
+                    "could not parse params file {}: {source}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParamsError::Read { source, .. } => Some(source),
+            ParamsError::Toml { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::{
+        input::{day_folder, InputSource},
+        run::deserialize_params,
+    };
+
+    use super::{default_path, parse_override, ParamsSource};
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(
+            default_path(&InputSource::File("day_14/example.txt".into()), 14),
+            Some(PathBuf::from("day_14/example.toml"))
+        );
+        assert_eq!(
+            default_path(&InputSource::InputsDir("inputs".into()), 14),
+            Some(PathBuf::from("inputs/day_14.toml"))
+        );
+        assert_eq!(
+            default_path(&InputSource::DayFolder, 14),
+            Some(day_folder(14).join("input.toml"))
+        );
+        assert_eq!(default_path(&InputSource::Stdin, 14), None);
+    }
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(
+            parse_override("width=11"),
+            Ok(("width".to_string(), toml::Value::Integer(11)))
+        );
+        assert_eq!(
+            parse_override(" fast = true"),
+            Ok(("fast".to_string(), toml::Value::Boolean(true)))
+        );
+        assert_eq!(
+            parse_override("name=\"a b\""),
+            Ok(("name".to_string(), toml::Value::String("a b".to_string())))
+        );
+
+        // Not TOML, so a plain string
+        assert_eq!(
+            parse_override("distance=squared"),
+            Ok((
+                "distance".to_string(),
+                toml::Value::String("squared".to_string())
+            ))
+        );
+
+        assert_eq!(
+            parse_override("rules . max_step=5"),
+            Ok(("rules.max_step".to_string(), toml::Value::Integer(5)))
+        );
+
+        assert!(parse_override("width").is_err());
+        assert!(parse_override(" =11").is_err());
+        assert!(parse_override("rules.=5").is_err());
+        assert!(parse_override(".max_step=5").is_err());
+    }
+
+    #[test]
+    fn test_overrides_win() {
+        let source = ParamsSource {
+            file: None,
+            overrides: vec![
+                ("width".to_string(), toml::Value::Integer(11)),
+                ("width".to_string(), toml::Value::Integer(7)),
+            ],
+        };
+
+        let params = source.load(&InputSource::Stdin, 14).unwrap();
+
+        assert_eq!(params.get("width"), Some(&toml::Value::Integer(7)));
+    }
+
+    #[test]
+    fn test_dotted_overrides() {
+        let override_of = |arg| parse_override(arg).unwrap();
+        let source = ParamsSource {
+            file: None,
+            overrides: vec![
+                override_of("rules={ min_step = 2, max_step = 4 }"),
+                override_of("rules.max_step=5"),
+                override_of("max_removals=2"),
+            ],
+        };
+
+        let params = source.load(&InputSource::Stdin, 2).unwrap();
+        let params = deserialize_params::<day_2::Day>(params).unwrap();
+
+        assert_eq!(
+            params,
+            day_2::Params {
+                max_removals: 2,
+                rules: day_2::Rules {
+                    min_step: 2,
+                    max_step: 5,
+                    ..day_2::Rules::default()
+                },
+                ..day_2::Params::default()
+            }
+        );
+
+        // Not a table any more
+        let source = ParamsSource {
+            file: None,
+            overrides: vec![override_of("width=11"), override_of("width.x=3")],
+        };
+        let params = source.load(&InputSource::Stdin, 14).unwrap();
+        assert_eq!(params.to_string(), "[width]\nx = 3\n");
+    }
+}
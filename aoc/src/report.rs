@@ -0,0 +1,215 @@
+use std::{cmp::Reverse, time::Duration};
+
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::{
+    all::Row,
+    answers::{AnswerStore, Verdict},
+    memory,
+    run::PartRun,
+};
+
+// How many of the slowest parts are highlighted in the summary table
+const HIGHLIGHTED_SLOWEST: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Ok,
+    Error,
+}
+
+// One line of `--format json` output, for one part of one day.
+// Every field is always present; the ones that don't apply are null.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    day: u8,
+    part: u8,
+    status: Status,
+    // Always a string, as some answers are text and numbers can get too big for JSON parsers
+    answer: Option<String>,
+    parse_time_ms: Option<f64>,
+    solve_time_ms: Option<f64>,
+    // Only counted with `--features memory`
+    peak_bytes: Option<usize>,
+    allocated_bytes: Option<usize>,
+    allocations: Option<usize>,
+    error: Option<String>,
+    // "correct", "wrong" or "unknown" compared to the answer store, null without one
+    verdict: Option<&'static str>,
+    // "too_high" or "too_low" if a wrong answer is known to be
+    hint: Option<&'static str>,
+}
+
+impl Record {
+    pub fn solved(day: u8, parse_time: Duration, part_run: &PartRun) -> Self {
+        Self {
+            day,
+            part: part_run.part,
+            status: Status::Ok,
+            answer: match &part_run.answer {
+                common::Answer::None => None,
+                answer => Some(answer.to_string()),
+            },
+            parse_time_ms: Some(millis(parse_time)),
+            solve_time_ms: part_run.time.map(millis),
+            peak_bytes: part_run.memory.map(|memory| memory.peak),
+            allocated_bytes: part_run.memory.map(|memory| memory.allocated),
+            allocations: part_run.memory.map(|memory| memory.allocations),
+            error: None,
+            verdict: None,
+            hint: None,
+        }
+    }
+
+    // `parse_time` is None if the input could not even be read
+    pub fn failed(day: u8, part: u8, parse_time: Option<Duration>, error: String) -> Self {
+        Self {
+            day,
+            part,
+            status: Status::Error,
+            answer: None,
+            parse_time_ms: parse_time.map(millis),
+            solve_time_ms: None,
+            peak_bytes: None,
+            allocated_bytes: None,
+            allocations: None,
+            error: Some(error),
+            verdict: None,
+            hint: None,
+        }
+    }
+
+    pub fn with_verdict(self, verdict: Option<&Verdict>) -> Self {
+        Self {
+            verdict: verdict.map(Verdict::name),
+            hint: verdict.and_then(Verdict::hint),
+            ..self
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{}",
+            serde_json::to_string(self).expect("records only contain plain values")
+        );
+    }
+}
+
+impl From<&Row> for Record {
+    fn from(row: &Row) -> Self {
+        match &row.result {
+            Ok(part_run) => Record::solved(
+                row.day,
+                row.parse_time.expect("solved parts were parsed first"),
+                part_run,
+            ),
+            Err(error) => Record::failed(row.day, row.part, row.parse_time, error.clone()),
+        }
+    }
+}
+
+// Prints one line per row, with the slowest parts in yellow and failures in red. With an answer
+// store every answer is also marked as correct, wrong or unknown, and with `--features memory`
+// the memory each part used is shown next to its time.
+pub fn print_table(rows: &[Row], wall_time: Duration, answers: Option<&AnswerStore>) {
+    let mut solved: Vec<&PartRun> = rows
+        .iter()
+        .filter_map(|row| row.result.as_ref().ok())
+        .collect();
+    solved.sort_by_key(|part_run| Reverse(part_run.time));
+    let slowest_time = solved
+        .get(HIGHLIGHTED_SLOWEST.min(solved.len()).saturating_sub(1))
+        .and_then(|part_run| part_run.time);
+
+    // Errors are left out, they would make the column far too wide
+    let answer_width = solved
+        .iter()
+        .map(|part_run| part_run.answer.to_string().len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut header = format!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>10}",
+        "Day", "Part", "Answer", "Time"
+    );
+    if memory::COUNTING {
+        header += &format!(
+            "  {:>10}  {:>10}  {:>11}",
+            "Peak", "Allocated", "Allocations"
+        );
+    }
+    println!("{header}");
+
+    for row in rows {
+        match &row.result {
+            Ok(part_run) => {
+                let mut line = format!(
+                    "{:>3}  {:>4}  {:<answer_width$}  {:>10}",
+                    row.day,
+                    row.part,
+                    part_run.answer.to_string(),
+                    part_run
+                        .time
+                        .map_or("-".to_string(), |time| format!("{time:.2?}")),
+                );
+                if let Some(usage) = part_run.memory {
+                    line += &format!(
+                        "  {:>10}  {:>10}  {:>11}",
+                        memory::bytes(usage.peak),
+                        memory::bytes(usage.allocated),
+                        usage.allocations
+                    );
+                }
+
+                let line = if slowest_time
+                    .is_some_and(|slowest_time| part_run.time >= Some(slowest_time))
+                {
+                    line.yellow().bold()
+                } else {
+                    line.normal()
+                };
+
+                match row.check(answers) {
+                    Some(verdict) => println!("{line}  {}", verdict.colored()),
+                    None => println!("{line}"),
+                }
+            }
+            Err(error) => {
+                let line = format!("{:>3}  {:>4}  error: {error}", row.day, row.part);
+                println!("{}", line.red());
+            }
+        }
+    }
+
+    println!();
+    print!(
+        "{} of {} parts solved in {wall_time:.2?}",
+        solved.len(),
+        rows.len()
+    );
+
+    if answers.is_some() {
+        let verdicts: Vec<Verdict> = rows.iter().filter_map(|row| row.check(answers)).collect();
+        let count = |name| {
+            verdicts
+                .iter()
+                .filter(|verdict| verdict.name() == name)
+                .count()
+        };
+
+        print!(
+            ", {} correct and {} wrong",
+            count("correct"),
+            count("wrong")
+        );
+    }
+
+    println!();
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,283 @@
+use std::{
+    fmt::{self, Display},
+    io::BufRead,
+    time::{Duration, Instant},
+};
+
+use common::{Answer, ParseError, Solution};
+
+use crate::{
+    input::InputSource,
+    memory::{self, MemoryUsage},
+    Part,
+};
+
+// The answer to one part of a day, how long it took to find it, and with `--features memory`
+// how much memory that took. Both are None for parts that aren't solved on their own, like
+// the ones of `stream_day`.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Option<Duration>,
+    pub memory: Option<MemoryUsage>,
+}
+
+// Everything that happened while running (some parts of) a day
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u8,
+    pub parse_time: Duration,
+    // Only counted by `stream_day`, where parsing does all the work
+    pub parse_memory: Option<MemoryUsage>,
+    pub result: Result<Vec<PartRun>, DayError>,
+}
+
+// Why a day could not be run at all
+#[derive(Debug, Clone)]
+pub enum DayError {
+    // The parameters don't fit the day's `Params`, like a misspelt name or a value of the wrong type
+    Params(String),
+    // Only while streaming, otherwise the input is read before the day runs
+    Read(String),
+    Parse(ParseError),
+}
+
+impl DayError {
+    // A one line description that says which input or day the error is about
+    pub fn describe(&self, source: &InputSource, day: u8) -> String {
+        match self {
+            DayError::Params(message) => format!("invalid parameters for day {day}: {message}"),
+            DayError::Read(message) => {
+                format!("could not read {}: {message}", source.describe(day))
+            }
+            DayError::Parse(err) => format!("could not parse {}: {err}", source.describe(day)),
+        }
+    }
+}
+
+impl Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayError::Params(message) => write!(f, "invalid parameters: {message}"),
+            DayError::Read(message) => write!(f, "could not read the input: {message}"),
+            DayError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+// Runs the parts of `day` selected by `part` with the given parameters (any that are left out
+// keep their defaults), calling `on_part` as soon as each one is done
+pub fn run_day(
+    day: u8,
+    part: Part,
+    input: &str,
+    params: toml::Table,
+    on_part: impl FnMut(&PartRun),
+) -> DayRun {
+    match day {
+        1 => run::<day_1::Day>(day, part, input, params, on_part),
+        2 => run::<day_2::Day>(day, part, input, params, on_part),
+        3 => run::<day_3::Day>(day, part, input, params, on_part),
+        4 => run::<day_4::Day>(day, part, input, params, on_part),
+        5 => run::<day_5::Day>(day, part, input, params, on_part),
+        6 => run::<day_6::Day>(day, part, input, params, on_part),
+        7 => run::<day_7::Day>(day, part, input, params, on_part),
+        8 => run::<day_8::Day>(day, part, input, params, on_part),
+        9 => run::<day_9::Day>(day, part, input, params, on_part),
+        10 => run::<day_10::Day>(day, part, input, params, on_part),
+        11 => run::<day_11::Day>(day, part, input, params, on_part),
+        12 => run::<day_12::Day>(day, part, input, params, on_part),
+        13 => run::<day_13::Day>(day, part, input, params, on_part),
+        14 => run::<day_14::Day>(day, part, input, params, on_part),
+        15 => run::<day_15::Day>(day, part, input, params, on_part),
+        16 => run::<day_16::Day>(day, part, input, params, on_part),
+        17 => run::<day_17::Day>(day, part, input, params, on_part),
+        18 => run::<day_18::Day>(day, part, input, params, on_part),
+        19 => run::<day_19::Day>(day, part, input, params, on_part),
+        20 => run::<day_20::Day>(day, part, input, params, on_part),
+        21 => run::<day_21::Day>(day, part, input, params, on_part),
+        22 => run::<day_22::Day>(day, part, input, params, on_part),
+        23 => run::<day_23::Day>(day, part, input, params, on_part),
+        24 => run::<day_24::Day>(day, part, input, params, on_part),
+        25 => run::<day_25::Day>(day, part, input, params, on_part),
+        _ => unreachable!("there are only days 1 to 25"),
+    }
+}
+
+fn run<S: Solution>(
+    day: u8,
+    part: Part,
+    input: &str,
+    params: toml::Table,
+    mut on_part: impl FnMut(&PartRun),
+) -> DayRun {
+    let failed = |parse_time, err| DayRun {
+        day,
+        parse_time,
+        parse_memory: None,
+        result: Err(err),
+    };
+
+    let params = match deserialize_params::<S>(params) {
+        Ok(params) => params,
+        Err(err) => return failed(Duration::ZERO, err),
+    };
+
+    let time = Instant::now();
+    let data = S::parse(input, params);
+    let parse_time = time.elapsed();
+
+    let data = match data {
+        Ok(data) => data,
+        Err(err) => return failed(parse_time, DayError::Parse(err)),
+    };
+
+    let mut parts = vec![];
+
+    let mut solve = |number, solve_part: fn(&S::Input) -> Answer| {
+        let time = Instant::now();
+        let (answer, memory) = memory::measure(|| solve_part(&data));
+        let part_run = PartRun {
+            part: number,
+            answer,
+            time: Some(time.elapsed()),
+            memory,
+        };
+
+        on_part(&part_run);
+        parts.push(part_run);
+    };
+
+    if part.includes_one() {
+        solve(1, S::part_one);
+    }
+
+    if part.includes_two() {
+        solve(2, S::part_two);
+    }
+
+    DayRun {
+        day,
+        parse_time,
+        parse_memory: None,
+        result: Ok(parts),
+    }
+}
+
+// The days with `--stream`, which read the input a line at a time instead of all at once
+pub const STREAMING_DAYS: [u8; 1] = [1];
+
+// Like `run_day`, for one of the `STREAMING_DAYS`
+pub fn stream_day(
+    day: u8,
+    part: Part,
+    reader: impl BufRead,
+    params: toml::Table,
+    on_part: impl FnMut(&PartRun),
+) -> DayRun {
+    match day {
+        1 => stream_day_1(part, reader, params, on_part),
+        _ => unreachable!("only days {STREAMING_DAYS:?} can stream their input"),
+    }
+}
+
+// Both parts come out of the same pass over the input, so its time and memory are those of
+// the parsing, and the parts aren't measured on their own
+fn stream_day_1(
+    part: Part,
+    reader: impl BufRead,
+    params: toml::Table,
+    mut on_part: impl FnMut(&PartRun),
+) -> DayRun {
+    let failed = |parse_time, err| DayRun {
+        day: 1,
+        parse_time,
+        parse_memory: None,
+        result: Err(err),
+    };
+
+    let params = match deserialize_params::<day_1::Day>(params) {
+        Ok(params) => params,
+        Err(err) => return failed(Duration::ZERO, err),
+    };
+
+    let time = Instant::now();
+    let (scores, parse_memory) = memory::measure(|| day_1::reconcile(reader, params));
+    let parse_time = time.elapsed();
+
+    let scores = match scores {
+        Ok(scores) => scores,
+        Err(day_1::ReconcileError::Read(err)) => {
+            return failed(parse_time, DayError::Read(err.to_string()))
+        }
+        Err(day_1::ReconcileError::Parse(err)) => return failed(parse_time, DayError::Parse(err)),
+    };
+
+    let parts = part
+        .numbers()
+        .map(|number| {
+            let answer = match number {
+                1 => scores.distance,
+                _ => scores.similarity,
+            };
+            let part_run = PartRun {
+                part: number,
+                answer: answer.into(),
+                time: None,
+                memory: None,
+            };
+
+            on_part(&part_run);
+            part_run
+        })
+        .collect();
+
+    DayRun {
+        day: 1,
+        parse_time,
+        parse_memory,
+        result: Ok(parts),
+    }
+}
+
+// Any parameters that are left out keep their defaults
+pub fn deserialize_params<S: Solution>(params: toml::Table) -> Result<S::Params, DayError> {
+    toml::Value::Table(params)
+        .try_into()
+        .map_err(|err: toml::de::Error| DayError::Params(err.message().to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use common::Answer;
+
+    use super::{run_day, stream_day, DayError, DayRun};
+    use crate::Part;
+
+    fn answers(run: DayRun) -> Vec<Answer> {
+        run.result
+            .unwrap()
+            .into_iter()
+            .map(|part_run| part_run.answer)
+            .collect()
+    }
+
+    #[test]
+    fn test_stream_day() {
+        let input = include_str!("../../day_1/example.txt");
+        let stream =
+            |part, input: &str| stream_day(1, part, input.as_bytes(), toml::Table::new(), |_| {});
+
+        assert_eq!(
+            answers(stream(Part::All, input)),
+            answers(run_day(1, Part::All, input, toml::Table::new(), |_| {}))
+        );
+        assert_eq!(answers(stream(Part::Two, input)), [Answer::from(31u64)]);
+
+        let Err(DayError::Parse(err)) = stream(Part::All, "1 2\n3 x\n").result else {
+            panic!("the second line has no second number");
+        };
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
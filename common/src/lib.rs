@@ -0,0 +1,185 @@
+pub mod testing;
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+
+// The value a part produces. Most days end in a number, a few in a string
+// (day 17's program output, day 23's password, day 24's wire list).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    // A part that has no puzzle behind it, like day 25 part two
+    None,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::None, Into::into)
+    }
+}
+
+// Where and why a day's input could not be parsed.
+// Lines and columns start at 1, like in an editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    // `text` should be a slice of `input`, that way its position can be worked out from the pointers.
+    // Anything else is searched for in the input instead.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= input.len() && input.is_char_boundary(*offset))
+            .or_else(|| input.find(text))
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self::new(line, column, text, message)
+    }
+
+    // Like `at`, but points at the end of the input (for things that are missing)
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, " (found {:?})", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parses `text` (a slice of `input`) with `FromStr`, pointing at it if that fails
+pub fn parse_at<T>(input: &str, text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|err| ParseError::at(input, text, format!("invalid value: {err}")))
+}
+
+// Every day implements this on a unit struct, so the runner (and anything else
+// that wants answers) can treat all days the same way.
+pub trait Solution {
+    type Input;
+    // Constants the puzzle text gives, which differ between the examples and the real
+    // puzzle, like the size of the room in day 14. The defaults are for the real puzzle,
+    // the runner can override them from a TOML file or the command line.
+    type Params: Default + DeserializeOwned;
+
+    fn parse(input: &str, params: Self::Params) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+}
+
+// The `Params` of the days that always run the same way
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse_at, ParseError};
+
+    #[test]
+    fn test_error_position_from_slice() {
+        let input = "1 2\n3 x4\n";
+        let text = &input[6..8];
+
+        let err = ParseError::at(input, text, "not a number");
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x4"));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: not a number (found \"x4\")"
+        );
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let input = "12 ab";
+
+        assert_eq!(parse_at::<u32>(input, &input[..2]), Ok(12));
+
+        let err = parse_at::<u32>(input, &input[3..]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+}
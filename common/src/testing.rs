@@ -0,0 +1,126 @@
+// Checks shared by the answer tests of every day
+use std::{fs, io::ErrorKind, path::Path};
+
+use crate::{Answer, Solution};
+
+fn parse<S: Solution>(input: &str, params: S::Params) -> S::Input {
+    S::parse(input, params).unwrap_or_else(|err| panic!("could not parse the input: {err}"))
+}
+
+pub fn check_part_one<S: Solution>(input: &str, expected: impl Into<Answer>) {
+    check_part_one_with::<S>(input, S::Params::default(), expected);
+}
+
+pub fn check_part_two<S: Solution>(input: &str, expected: impl Into<Answer>) {
+    check_part_two_with::<S>(input, S::Params::default(), expected);
+}
+
+pub fn check_example<S: Solution>(
+    input: &str,
+    part_one: impl Into<Answer>,
+    part_two: impl Into<Answer>,
+) {
+    check_example_with::<S>(input, S::Params::default(), part_one, part_two);
+}
+
+// The `_with` variants are for examples that need other constants than the real puzzle
+pub fn check_part_one_with<S: Solution>(
+    input: &str,
+    params: S::Params,
+    expected: impl Into<Answer>,
+) {
+    assert_eq!(
+        S::part_one(&parse::<S>(input, params)),
+        expected.into(),
+        "part one"
+    );
+}
+
+pub fn check_part_two_with<S: Solution>(
+    input: &str,
+    params: S::Params,
+    expected: impl Into<Answer>,
+) {
+    assert_eq!(
+        S::part_two(&parse::<S>(input, params)),
+        expected.into(),
+        "part two"
+    );
+}
+
+pub fn check_example_with<S: Solution>(
+    input: &str,
+    params: S::Params,
+    part_one: impl Into<Answer>,
+    part_two: impl Into<Answer>,
+) {
+    let data = parse::<S>(input, params);
+
+    assert_eq!(S::part_one(&data), part_one.into(), "part one");
+    assert_eq!(S::part_two(&data), part_two.into(), "part two");
+}
+
+// Compares the answers for the personal `input.txt` in `day_dir` with the ones in
+// `answers.txt` next to it: part one on the first line, part two on the second.
+// Inputs are personal, so if either file is missing there is nothing to check.
+// A part with an empty (or missing) line is not run at all.
+pub fn check_personal_answers<S: Solution>(day_dir: &str) {
+    check_personal_part::<S>(day_dir, 1);
+    check_personal_part::<S>(day_dir, 2);
+}
+
+// Like `check_personal_answers` for only one part, so a part that takes minutes can be
+// checked in a test of its own that is `#[ignore]`d and run with `cargo test -- --ignored`
+pub fn check_personal_part<S: Solution>(day_dir: &str, part: u8) {
+    let day_dir = Path::new(day_dir);
+    let (Some(input), Some(answers)) = (
+        read_optional(&day_dir.join("input.txt")),
+        read_optional(&day_dir.join("answers.txt")),
+    ) else {
+        eprintln!(
+            "no input.txt or answers.txt in {}, skipping",
+            day_dir.display()
+        );
+        return;
+    };
+
+    let Some(expected) = answers
+        .lines()
+        .map(str::trim)
+        .nth(usize::from(part) - 1)
+        .filter(|line| !line.is_empty())
+    else {
+        return;
+    };
+
+    let data = parse::<S>(&input, S::Params::default());
+
+    let answer = match part {
+        1 => S::part_one(&data),
+        2 => S::part_two(&data),
+        _ => panic!("there is no part {part}"),
+    };
+
+    assert_eq!(answer.to_string(), expected, "part {part}");
+}
+
+fn read_optional(path: &Path) -> Option<String> {
+    match fs::read_to_string(path) {
+        Ok(text) => Some(text),
+        Err(err) if err.kind() == ErrorKind::NotFound => None,
+        Err(err) => panic!("could not read {}: {err}", path.display()),
+    }
+}
+
+// Inputs from a day's `generate` have to parse, and part one has to get through them
+// (part two is left out, it takes minutes on some days)
+pub fn check_generated_inputs<S: Solution>(generate: fn(usize, u64) -> String, size: usize) {
+    for seed in 0..5 {
+        let input = generate(size, seed);
+        let data = S::parse(&input, S::Params::default()).unwrap_or_else(|err| {
+            panic!("the generated input for size {size} and seed {seed} does not parse: {err}")
+        });
+
+        S::part_one(&data);
+    }
+}
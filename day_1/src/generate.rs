@@ -0,0 +1,23 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// `size` pairs of location IDs. About half of the right list is copied from the left one,
+// so the similarity score of part two isn't always zero.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let left: Vec<u64> = (0..size).map(|_| rng.gen_range(10000..100000)).collect();
+
+    let mut input = String::new();
+    for first in &left {
+        let second = if rng.gen_bool(0.5) {
+            left[rng.gen_range(0..left.len())]
+        } else {
+            rng.gen_range(10000..100000)
+        };
+
+        input.push_str(&format!("{first}   {second}\n"));
+    }
+
+    input
+}
@@ -0,0 +1,785 @@
+mod generate;
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    io::{self, BufRead},
+};
+
+use common::{parse_at, Answer, ParseError, Solution};
+use log::{debug, log_enabled, trace, Level};
+use serde::Deserialize;
+
+pub use generate::generate;
+
+// The puzzle has two lists side by side, but there can be more of them. The parts
+// compare the first two, all of them are compared with each other in `distance_matrix` and
+// `similarity_matrix`.
+#[derive(Debug)]
+pub struct Data {
+    lists: Vec<Vec<u64>>,
+    // How often every location ID shows up in each list
+    counts: Vec<HashMap<u64, u64>>,
+    params: Params,
+}
+
+// The lists while they are being read, one line at a time
+#[derive(Debug, Default)]
+struct Lists {
+    lists: Vec<Vec<u64>>,
+    counts: Vec<HashMap<u64, u64>>,
+}
+
+impl Lists {
+    // Adds the IDs on `line`, one to every list, blank lines are skipped. The first line says
+    // how many lists there are. The errors point into `line`, so they are always on line 1,
+    // whoever knows where the line came from has to fix that.
+    fn push_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let ids = line
+            .split_whitespace()
+            .map(|text| parse_at(line, text))
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        if self.lists.is_empty() {
+            if ids.len() < 2 {
+                return Err(ParseError::at_end(line, "expected at least two numbers"));
+            }
+
+            self.lists = vec![Vec::new(); ids.len()];
+            self.counts = vec![HashMap::new(); ids.len()];
+        }
+
+        if ids.len() != self.lists.len() {
+            let message = format!("expected {} numbers like the first line", self.lists.len());
+            return Err(match line.split_whitespace().nth(self.lists.len()) {
+                Some(extra) => ParseError::at(line, extra, message),
+                None => ParseError::at_end(line, message),
+            });
+        }
+
+        for ((list, counts), id) in self.lists.iter_mut().zip(&mut self.counts).zip(ids) {
+            list.push(id);
+            *counts.entry(id).or_default() += 1;
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self, params: Params) -> Data {
+        // Nothing to compare, but the parts still have two lists to look at
+        if self.lists.is_empty() {
+            self.lists = vec![Vec::new(); 2];
+            self.counts = vec![HashMap::new(); 2];
+        }
+
+        for list in &mut self.lists {
+            list.sort_unstable();
+        }
+
+        let data = Data {
+            lists: self.lists,
+            counts: self.counts,
+            params,
+        };
+
+        if params.report || params.matrices {
+            print_explanation(&data);
+        }
+
+        data
+    }
+}
+
+// How far apart two location IDs are
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Distance {
+    // The difference, like the puzzle wants
+    #[default]
+    Absolute,
+    // The difference squared, so a few pairs far apart stand out
+    Squared,
+    // The difference in millionths of the bigger ID
+    Relative,
+}
+
+impl Distance {
+    // None if it doesn't fit into a u64, which only happens when squaring
+    pub fn between(self, left: u64, right: u64) -> Option<u64> {
+        let difference = left.abs_diff(right);
+
+        match self {
+            Distance::Absolute => Some(difference),
+            Distance::Squared => difference.checked_mul(difference),
+            Distance::Relative if difference == 0 => Some(0),
+            Distance::Relative => {
+                Some((u128::from(difference) * 1_000_000 / u128::from(left.max(right))) as u64)
+            }
+        }
+    }
+}
+
+// How much an ID that shows up `left_count` times in the first list and `right_count` times
+// in the second adds to the similarity score
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Similarity {
+    // Every time in the first list, the ID times how often it is in the second, like the
+    // puzzle wants
+    #[default]
+    Occurrences,
+    // If it is in both lists, the ID times how often it is in either of them
+    Weighted,
+}
+
+impl Similarity {
+    // None if it doesn't fit into a u64
+    pub fn score(self, id: u64, left_count: u64, right_count: u64) -> Option<u64> {
+        match self {
+            Similarity::Occurrences => id.checked_mul(right_count)?.checked_mul(left_count),
+            Similarity::Weighted if left_count == 0 || right_count == 0 => Some(0),
+            Similarity::Weighted => id.checked_mul(left_count.checked_add(right_count)?),
+        }
+    }
+}
+
+// Which metrics the parts use, the defaults give the puzzle's answers. Set them with
+// `--param distance=squared` to see how the lists compare in other ways.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub distance: Distance,
+    pub similarity: Similarity,
+    // Print where the answers come from to stderr: every pair with its distance and the
+    // `DistanceReport`, and every ID with what it adds to the similarity score
+    pub report: bool,
+    // Print the tables with the distance and similarity of every pair of lists to stderr once
+    // they are read, otherwise they are only logged at debug level if there are more than two
+    pub matrices: bool,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
+
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    let mut lists = Lists::default();
+
+    for (idx, line) in input.lines().enumerate() {
+        lists.push_line(line).map_err(|err| ParseError {
+            line: idx + 1,
+            ..err
+        })?;
+    }
+
+    Ok(lists.finish(params))
+}
+
+// Both answers at once, None if they don't fit into a u64
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scores {
+    pub distance: Option<u64>,
+    pub similarity: Option<u64>,
+}
+
+// Reads the lists straight from a file or any other reader, so the input never has to be in
+// memory as one string, only the IDs of the lists. Every line is read once.
+pub fn reconcile(mut reader: impl BufRead, params: Params) -> Result<Scores, ReconcileError> {
+    let mut lists = Lists::default();
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(ReconcileError::Read)? == 0 {
+            break;
+        }
+        line_number += 1;
+
+        // Like `str::lines`, without the line break
+        let text = line.trim_end_matches(['\n', '\r']);
+
+        lists.push_line(text).map_err(|err| {
+            ReconcileError::Parse(ParseError {
+                line: line_number,
+                ..err
+            })
+        })?;
+    }
+
+    let data = lists.finish(params);
+
+    Ok(Scores {
+        distance: part_one(&data),
+        similarity: part_two(&data),
+    })
+}
+
+#[derive(Debug)]
+pub enum ReconcileError {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReconcileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReconcileError::Read(source) => write!(f, "could not read the lists: {source}"),
+            ReconcileError::Parse(source) => write!(f, "{source}"),
+        }
+    }
+}
+
+impl std::error::Error for ReconcileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReconcileError::Read(source) => Some(source),
+            ReconcileError::Parse(source) => Some(source),
+        }
+    }
+}
+
+// The answers are None if they don't fit into a u64, which some metrics run into with big IDs
+pub fn part_one(data: &Data) -> Option<u64> {
+    if data.lists.len() > 2 {
+        debug!("{}", distance_matrix(data));
+    }
+
+    // Every pair at trace level is a lot of lines for the real input
+    if log_enabled!(Level::Debug) {
+        let report = distance_report(data)?;
+
+        if log_enabled!(Level::Trace) {
+            for line in format!("{report:#}").lines() {
+                trace!("{line}");
+            }
+        } else {
+            debug!("{report}");
+        }
+
+        return Some(report.total);
+    }
+
+    distance(data, 0, 1)
+}
+
+pub fn part_two(data: &Data) -> Option<u64> {
+    if data.lists.len() > 2 {
+        debug!("{}", similarity_matrix(data));
+    }
+
+    if log_enabled!(Level::Debug) {
+        let report = similarity_report(data);
+
+        if log_enabled!(Level::Trace) {
+            for line in format!("{report:#}").lines() {
+                trace!("{line}");
+            }
+        } else {
+            debug!("{report}");
+        }
+
+        return report.total;
+    }
+
+    similarity(data, 0, 1)
+}
+
+// What `report` and `matrices` ask for, printed to stderr once the lists are read. The log has
+// the same, but only while the parts run.
+fn print_explanation(data: &Data) {
+    if data.params.report {
+        match distance_report(data) {
+            Some(report) => eprintln!("{report:#}"),
+            None => eprintln!("{:?} distance -", data.params.distance),
+        }
+
+        eprintln!("{:#}", similarity_report(data));
+    }
+
+    if data.params.matrices {
+        eprintln!("{}", distance_matrix(data));
+        eprintln!("{}", similarity_matrix(data));
+    }
+}
+
+// Scores that don't fit into a u64 are shown like parts without an answer
+fn show(score: Option<u64>) -> String {
+    score.map_or_else(|| "-".to_string(), |score| score.to_string())
+}
+
+// Between the lists with the indices `left` and `right`
+fn distance(data: &Data, left: usize, right: usize) -> Option<u64> {
+    data.lists[left]
+        .iter()
+        .zip(&data.lists[right])
+        .try_fold(0u64, |total, (left, right)| {
+            total.checked_add(data.params.distance.between(*left, *right)?)
+        })
+}
+
+fn similarity(data: &Data, left: usize, right: usize) -> Option<u64> {
+    data.counts[left]
+        .iter()
+        .try_fold(0u64, |total, (id, left_count)| {
+            let right_count = data.counts[right].get(id).copied().unwrap_or(0);
+            total.checked_add(
+                data.params
+                    .similarity
+                    .score(*id, *left_count, right_count)?,
+            )
+        })
+}
+
+// A score for every pair of lists, the rows are the left list and the columns the right one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    pub name: String,
+    // None where the score doesn't fit into a u64
+    pub scores: Vec<Vec<Option<u64>>>,
+}
+
+impl Matrix {
+    fn new(name: String, num_lists: usize, score: impl Fn(usize, usize) -> Option<u64>) -> Self {
+        Self {
+            name,
+            scores: (0..num_lists)
+                .map(|left| (0..num_lists).map(|right| score(left, right)).collect())
+                .collect(),
+        }
+    }
+}
+
+// A table with the lists numbered from 1, like the columns of the input:
+//
+//   Absolute distance
+//          1   2   3
+//      1   0  11   7
+//      ...
+impl Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .scores
+            .iter()
+            .flatten()
+            .map(|score| show(*score).len())
+            .chain([self.scores.len().to_string().len()])
+            .max()
+            .unwrap_or(1);
+
+        write!(f, "{}\n{:>width$}", self.name, "")?;
+        for right in 1..=self.scores.len() {
+            write!(f, " {right:>width$}")?;
+        }
+
+        for (left, row) in self.scores.iter().enumerate() {
+            write!(f, "\n{:>width$}", left + 1)?;
+            for score in row {
+                write!(f, " {:>width$}", show(*score))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn distance_matrix(data: &Data) -> Matrix {
+    Matrix::new(
+        format!("{:?} distance", data.params.distance),
+        data.lists.len(),
+        |left, right| distance(data, left, right),
+    )
+}
+
+pub fn similarity_matrix(data: &Data) -> Matrix {
+    Matrix::new(
+        format!("{:?} similarity", data.params.similarity),
+        data.lists.len(),
+        |left, right| similarity(data, left, right),
+    )
+}
+
+// One pair of the sorted lists and how far apart they are
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub left: u64,
+    pub right: u64,
+    pub distance: u64,
+}
+
+impl Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} and {}: {}", self.left, self.right, self.distance)
+    }
+}
+
+// Where the total distance comes from. The statistics are 0 if the lists are empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceReport {
+    pub metric: Distance,
+    pub pairs: Vec<Pair>,
+    pub total: u64,
+    pub min: u64,
+    pub max: u64,
+    // The lower of the two middle distances if there is an even number of pairs
+    pub median: u64,
+}
+
+// Of the first two lists, None if a distance or the total doesn't fit into a u64
+pub fn distance_report(data: &Data) -> Option<DistanceReport> {
+    let metric = data.params.distance;
+
+    let pairs = data.lists[0]
+        .iter()
+        .zip(&data.lists[1])
+        .map(|(left, right)| {
+            Some(Pair {
+                left: *left,
+                right: *right,
+                distance: metric.between(*left, *right)?,
+            })
+        })
+        .collect::<Option<Vec<Pair>>>()?;
+
+    let mut distances: Vec<u64> = pairs.iter().map(|pair| pair.distance).collect();
+    distances.sort_unstable();
+
+    Some(DistanceReport {
+        metric,
+        total: distances
+            .iter()
+            .try_fold(0u64, |total, distance| total.checked_add(*distance))?,
+        min: distances.first().copied().unwrap_or(0),
+        max: distances.last().copied().unwrap_or(0),
+        median: distances
+            .get(distances.len().saturating_sub(1) / 2)
+            .copied()
+            .unwrap_or(0),
+        pairs,
+    })
+}
+
+// `{:#}` has a line for every pair before the statistics
+impl Display for DistanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            for pair in &self.pairs {
+                writeln!(f, "{pair}")?;
+            }
+        }
+
+        write!(
+            f,
+            "{:?} distance {} over {} pairs, min {}, max {}, median {}",
+            self.metric,
+            self.total,
+            self.pairs.len(),
+            self.min,
+            self.max,
+            self.median
+        )
+    }
+}
+
+// One ID of the first list and what it adds to the similarity score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdScore {
+    pub id: u64,
+    pub left_count: u64,
+    pub right_count: u64,
+    // None if it doesn't fit into a u64
+    pub score: Option<u64>,
+}
+
+impl Display for IdScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} times left, {} times right, scores {}",
+            self.id,
+            self.left_count,
+            self.right_count,
+            show(self.score)
+        )
+    }
+}
+
+// Where the similarity score comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimilarityReport {
+    pub metric: Similarity,
+    // The IDs that add anything, smallest first
+    pub ids: Vec<IdScore>,
+    pub total: Option<u64>,
+}
+
+// Of the first two lists
+pub fn similarity_report(data: &Data) -> SimilarityReport {
+    let metric = data.params.similarity;
+
+    let mut ids: Vec<IdScore> = data.counts[0]
+        .iter()
+        .map(|(id, left_count)| {
+            let right_count = data.counts[1].get(id).copied().unwrap_or(0);
+            IdScore {
+                id: *id,
+                left_count: *left_count,
+                right_count,
+                score: metric.score(*id, *left_count, right_count),
+            }
+        })
+        .filter(|id_score| id_score.score != Some(0))
+        .collect();
+    ids.sort_unstable_by_key(|id_score| id_score.id);
+
+    SimilarityReport {
+        metric,
+        ids,
+        total: similarity(data, 0, 1),
+    }
+}
+
+// `{:#}` has a line for every ID before the total
+impl Display for SimilarityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            for id_score in &self.ids {
+                writeln!(f, "{id_score}")?;
+            }
+        }
+
+        write!(f, "{:?} similarity {}", self.metric, show(self.total))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{
+        distance_matrix, distance_report, generate, parse, part_one, part_two, reconcile,
+        similarity_matrix, similarity_report, Day, Distance, Pair, Params, ReconcileError, Scores,
+        Similarity,
+    };
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 11, 31);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 100);
+    }
+
+    #[test]
+    fn test_blank_and_trailing_lines() {
+        let input = "3   4\n4   3\n\n2   5\r\n1   3\n3   9\n3   3\n\n\n";
+
+        assert_eq!(
+            reconcile(input.as_bytes(), Params::default()).unwrap(),
+            Scores {
+                distance: Some(11),
+                similarity: Some(31)
+            }
+        );
+
+        let data = parse(input, Params::default()).unwrap();
+        assert_eq!((part_one(&data), part_two(&data)), (Some(11), Some(31)));
+    }
+
+    #[test]
+    fn test_reconcile_errors() {
+        let Err(ReconcileError::Parse(err)) =
+            reconcile("1 2\n\n3 x4\n".as_bytes(), Params::default())
+        else {
+            panic!("the third line has no second number");
+        };
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 3, "x4"));
+
+        let Err(ReconcileError::Parse(err)) = reconcile("1 2\n3\n".as_bytes(), Params::default())
+        else {
+            panic!("the second line has only one number");
+        };
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(parse("1 2\n3\n", Params::default()).unwrap_err(), err);
+    }
+
+    #[test]
+    fn test_metrics() {
+        let input = include_str!("../example.txt");
+        let scores = |distance, similarity| {
+            let data = parse(
+                input,
+                Params {
+                    distance,
+                    similarity,
+                    ..Params::default()
+                },
+            )
+            .unwrap();
+            (part_one(&data), part_two(&data))
+        };
+
+        assert_eq!(
+            scores(Distance::Absolute, Similarity::Occurrences),
+            (Some(11), Some(31))
+        );
+        assert_eq!(
+            scores(Distance::Squared, Similarity::Weighted),
+            (Some(35), Some(26))
+        );
+        assert_eq!(
+            scores(Distance::Relative, Similarity::Occurrences).0,
+            Some(2205554)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let squared = Params {
+            distance: Distance::Squared,
+            ..Params::default()
+        };
+
+        let data = parse("0 4294967295\n", squared).unwrap();
+        assert_eq!(part_one(&data), Some(18446744065119617025));
+        let data = parse("0 4294967296\n", squared).unwrap();
+        assert_eq!(part_one(&data), None);
+        assert_eq!(distance_report(&data), None);
+        let data = parse("0 4294967295\n1 4294967295\n", squared).unwrap();
+        assert_eq!(part_one(&data), None);
+
+        // 2^62 twice in both lists
+        let data = parse(
+            &"4611686018427387904 4611686018427387904\n".repeat(2),
+            Params::default(),
+        )
+        .unwrap();
+        assert_eq!(part_two(&data), None);
+        assert_eq!(
+            similarity_matrix(&data).to_string(),
+            "Occurrences similarity\n  1 2\n1 - -\n2 - -"
+        );
+        assert_eq!(Similarity::Occurrences.score(u64::MAX, 2, 0), Some(0));
+        assert_eq!(Similarity::Weighted.score(u64::MAX, 1, 1), None);
+    }
+
+    #[test]
+    fn test_distance_report() {
+        let data = parse(include_str!("../example.txt"), Params::default()).unwrap();
+        let report = distance_report(&data).unwrap();
+
+        assert_eq!(
+            report.pairs[5],
+            Pair {
+                left: 4,
+                right: 9,
+                distance: 5
+            }
+        );
+        assert_eq!(
+            (report.total, report.min, report.max, report.median),
+            (11, 0, 5, 1)
+        );
+        assert_eq!(
+            report.to_string(),
+            "Absolute distance 11 over 6 pairs, min 0, max 5, median 1"
+        );
+        assert!(format!("{report:#}").starts_with("1 and 3: 2\n2 and 3: 1\n"));
+        assert!(format!("{report:#}").ends_with(&format!("\n4 and 9: 5\n{report}")));
+    }
+
+    #[test]
+    fn test_similarity_report() {
+        let data = parse(include_str!("../example.txt"), Params::default()).unwrap();
+        let report = similarity_report(&data);
+
+        assert_eq!(report.total, Some(31));
+        assert_eq!(
+            format!("{report:#}"),
+            "3: 3 times left, 3 times right, scores 27\n4: 1 times left, 1 times right, scores 4\nOccurrences similarity 31"
+        );
+    }
+
+    #[test]
+    fn test_more_lists() {
+        let data = parse("3 4 1\n4 3 3\n2 5 3\n", Params::default()).unwrap();
+
+        assert_eq!((part_one(&data), part_two(&data)), (Some(3), Some(7)));
+
+        let distances = distance_matrix(&data);
+        assert_eq!(
+            distances.scores,
+            [[0, 3, 2], [3, 0, 5], [2, 5, 0]].map(|row| row.map(Some))
+        );
+        assert_eq!(
+            distances.to_string(),
+            "Absolute distance\n  1 2 3\n1 0 3 2\n2 3 0 5\n3 2 5 0"
+        );
+
+        let similarities = similarity_matrix(&data);
+        assert_eq!(
+            similarities.scores,
+            [[9, 7, 6], [7, 12, 6], [6, 6, 13]].map(|row| row.map(Some))
+        );
+
+        let err = parse("1 2 3\n4 5\n", Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse("1 2\n4 5 6\n", Params::default()).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "6"));
+    }
+
+    #[test]
+    fn test_matrices() {
+        let params = Params {
+            distance: Distance::Squared,
+            similarity: Similarity::Weighted,
+            ..Params::default()
+        };
+        let data = parse("1 4 1 9\n2 4 3 9\n3 1 3 9\n", params).unwrap();
+
+        assert_eq!(
+            distance_matrix(&data).scores,
+            [
+                [0, 5, 1, 149],
+                [5, 0, 2, 114],
+                [1, 2, 0, 136],
+                [149, 114, 136, 0]
+            ]
+            .map(|row| row.map(Some))
+        );
+        assert_eq!(
+            similarity_matrix(&data).scores,
+            [[12, 2, 11, 0], [2, 18, 2, 0], [11, 2, 14, 0], [0, 0, 0, 54]].map(|row| row.map(Some))
+        );
+        assert_eq!(
+            similarity_matrix(&data).to_string(),
+            "Weighted similarity\n    1  2  3  4\n 1 12  2 11  0\n 2  2 18  2  0\n 3 11  2 14  0\n 4  0  0  0 54"
+        );
+    }
+}
@@ -0,0 +1,28 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use grid::{Grid, Pos};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// A `size` x `size` topographic map. Random heights hardly ever form a hiking trail, so a
+// few random walks from 0 up to 9 are drawn on top of them.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let mut map = Grid::from_fn(size, size, |_| rng.gen_range(0..=9_u32));
+
+    for _ in 0..size * size / 20 + 1 {
+        let mut pos = Pos::new(rng.gen_range(0..size), rng.gen_range(0..size));
+
+        for height in 0..=9 {
+            map[pos] = height;
+
+            let neighbours: Vec<Pos> = map.neighbours4(pos).collect();
+            let Some(next) = neighbours.choose(&mut rng) else {
+                break;
+            };
+            pos = *next;
+        }
+    }
+
+    map.to_string()
+}
@@ -0,0 +1,184 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use grid::{Grid, Pos};
+use render::{Cell, Color, Exploration, Explore, Frame};
+use std::{collections::HashSet, fmt::Write};
+
+use colored::Colorize;
+use log::{debug, trace};
+
+pub use generate::generate;
+
+pub struct Data {
+    map: Grid<u32>,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |pos, c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new(pos.y + 1, pos.x + 1, c, "expected a height digit"))
+        })?;
+
+        Ok(Self { map })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+// The heights, lighter the higher they are, with the score and rating of every trailhead
+impl Explore for Day {
+    fn explore(data: &Data) -> Exploration<'_> {
+        let with_height = |height| {
+            data.map
+                .iter()
+                .filter(move |(_, h)| **h == height)
+                .map(|(pos, _)| pos)
+        };
+
+        let map = Frame::new(&data.map, |_, height| {
+            let light = 60 + 20 * *height as u8;
+            Cell::new(char::from_digit(*height, 10).unwrap_or('?')).fg(Color::TrueColor {
+                r: light,
+                g: light,
+                b: light,
+            })
+        });
+
+        Exploration::new(map)
+            .with_overlay("trailheads", Color::Green, with_height(0))
+            .with_overlay("hilltops", Color::Red, with_height(9))
+            .with_inspector(move |pos| match data.map[pos] {
+                0 => format!(
+                    "height 0, a trailhead with score {} and rating {}",
+                    count_hilltops(data, pos),
+                    count_paths(data, pos)
+                ),
+                9 => format!(
+                    "height 9, reachable from {} trailheads",
+                    with_height(0)
+                        .filter(|trailhead| reachable_hilltops(data, *trailhead).contains(&pos))
+                        .count()
+                ),
+                height => format!("height {height}"),
+            })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    sum_trailheads(data, |data, pos| {
+        let count = count_hilltops(data, pos);
+        (count, format!("{count}"))
+    })
+}
+
+pub fn part_two(data: &Data) -> usize {
+    sum_trailheads(data, |data, pos| {
+        let count = count_paths(data, pos);
+        (count, format!("{count:x}"))
+    })
+}
+
+// Logs the map with the score of every trailhead in place of its 0
+fn sum_trailheads(data: &Data, score: impl Fn(&Data, Pos) -> (usize, String)) -> usize {
+    let mut sum = 0;
+    let mut map = String::new();
+    for (pos, height) in data.map.iter() {
+        if *height == 0 {
+            let (count, text) = score(data, pos);
+            sum += count;
+            write!(map, "{}", text.red()).unwrap();
+        } else {
+            write!(map, "{height}").unwrap();
+        }
+
+        if pos.x == data.map.width() - 1 {
+            map.push('\n');
+        }
+    }
+
+    debug!("trailhead scores:\n{map}");
+
+    sum
+}
+
+fn count_hilltops(data: &Data, pos: Pos) -> usize {
+    reachable_hilltops(data, pos).len()
+}
+
+fn reachable_hilltops(data: &Data, pos: Pos) -> HashSet<Pos> {
+    let current_height = data.map[pos];
+
+    let mut positions = HashSet::new();
+    positions.insert(pos);
+
+    for next_height in (current_height + 1)..=9 {
+        positions = HashSet::from_iter(positions.iter().flat_map(|pos| {
+            data.map
+                .neighbours4(*pos)
+                .filter(|next| data.map[*next] == next_height)
+        }));
+    }
+
+    positions
+}
+
+fn count_paths(data: &Data, current_pos: Pos) -> usize {
+    trace!("at {current_pos:?}");
+    let current_height = data.map[current_pos];
+
+    if current_height == 9 {
+        trace!("reached a hilltop");
+        return 1;
+    }
+
+    data.map
+        .neighbours4(current_pos)
+        .filter(|next| data.map[*next] == current_height + 1)
+        .map(|next| count_paths(data, next))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 36, 81);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 30);
+    }
+}
@@ -0,0 +1,16 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// `size` stones with numbers of up to 7 digits
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let stones: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.gen_range(1..=7);
+            rng.gen_range(0..10_u64.pow(digits)).to_string()
+        })
+        .collect();
+
+    format!("{}\n", stones.join(" "))
+}
@@ -0,0 +1,183 @@
+mod generate;
+
+use common::{parse_at, Answer, ParseError, Solution};
+use log::trace;
+use serde::Deserialize;
+use std::{collections::HashMap, iter};
+
+use rayon::iter::{ParallelDrainRange, ParallelIterator};
+
+pub use generate::generate;
+
+#[derive(Debug, Clone)]
+pub struct Data {
+    stones: Vec<u64>,
+    params: Params,
+}
+
+impl Data {
+    fn from_str(input: &str, params: Params) -> Result<Self, ParseError> {
+        let stones = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "expected a line of stones"))?
+            .split_ascii_whitespace()
+            .map(|s| parse_at(input, s))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { stones, params })
+    }
+}
+
+// How many times the stones blink in each part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part_one_blinks: usize,
+    pub part_two_blinks: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_one_blinks: 25,
+            part_two_blinks: 75,
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
+
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    Data::from_str(input, params)
+}
+
+pub fn part_one(data: &Data) -> u64 {
+    blink_dynamic(data, data.params.part_one_blinks)
+}
+
+pub fn part_two(data: &Data) -> u64 {
+    blink_dynamic(data, data.params.part_two_blinks)
+}
+
+pub fn blink_raw(data: &Data, n: usize) -> u64 {
+    let mut data = data.clone();
+
+    // This is too slow (and too memory hungry) to calculate any n over ~45, but is was great to validate my dynamic solution
+    for blink in 0..n {
+        // Blink n times
+        trace!("blink {blink}");
+
+        data.stones = data
+            .stones
+            .par_drain(..)
+            .flat_map_iter(|stone| {
+                let len = stone.checked_ilog10().unwrap_or(0) + 1;
+                let vals: Box<dyn Iterator<Item = u64>> = if stone == 0 {
+                    Box::new(iter::once(1))
+                } else if len % 2 == 0 {
+                    Box::new(
+                        iter::once(stone / 10_u64.pow(len / 2))
+                            .chain(iter::once(stone % 10_u64.pow(len / 2))),
+                    )
+                } else {
+                    Box::new(iter::once(stone * 2024))
+                };
+
+                vals
+            })
+            .collect();
+    }
+
+    data.stones.len() as u64
+}
+
+pub fn blink_dynamic(data: &Data, n: usize) -> u64 {
+    // This is slower when using par_iter and RwLock then just doing it single_threaded *shrug*
+    let mut lookup = HashMap::new();
+
+    data.stones
+        .iter()
+        .map(|stone| count_stones_for_for(*stone, n, &mut lookup))
+        .sum()
+}
+
+fn count_stones_for_for(stone: u64, n: usize, lookup: &mut HashMap<(u64, usize), u64>) -> u64 {
+    if let Some(v) = lookup.get(&(stone, n)) {
+        return *v;
+    }
+
+    if n == 0 {
+        lookup.insert((stone, n), 1);
+        return 1;
+    }
+
+    let len = stone.checked_ilog10().unwrap_or(0) + 1;
+    if stone == 0 {
+        let res = count_stones_for_for(1, n - 1, lookup);
+        lookup.insert((stone, n), res);
+        res
+    } else if len % 2 == 0 {
+        let res = count_stones_for_for(stone / 10_u64.pow(len / 2), n - 1, lookup)
+            + count_stones_for_for(stone % 10_u64.pow(len / 2), n - 1, lookup);
+        lookup.insert((stone, n), res);
+        res
+    } else {
+        let res = count_stones_for_for(stone * 2024, n - 1, lookup);
+        lookup.insert((stone, n), res);
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_generated_inputs, check_part_one, check_personal_answers};
+    use proptest::{prelude::prop, prop_assert_eq, proptest};
+
+    use crate::{blink_dynamic, blink_raw, generate, Data, Day, Params};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), 55312);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 8);
+    }
+
+    proptest! {
+        // blink_raw keeps every stone around, so only a few blinks are feasible
+        #[test]
+        fn test_blink_strategies_agree(
+            stones in prop::collection::vec(0..1_000_000_u64, 1..5),
+            blinks in 0..20_usize,
+        ) {
+            let data = Data { stones, params: Params::default() };
+
+            prop_assert_eq!(blink_dynamic(&data, blinks), blink_raw(&data, blinks));
+        }
+    }
+}
@@ -0,0 +1,24 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use grid::{Grid, Pos};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// A `size` x `size` garden. Each plot usually copies the plant of the plot above or left of it,
+// which grows regions of all kinds of shapes, including ones with holes.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut garden = Grid::new(size, size, 'A');
+    for y in 0..size {
+        for x in 0..size {
+            let plant = match rng.gen_range(0..10) {
+                0..=3 if y > 0 => garden[Pos::new(x, y - 1)],
+                4..=7 if x > 0 => garden[Pos::new(x - 1, y)],
+                _ => rng.gen_range('A'..='Z'),
+            };
+
+            garden[Pos::new(x, y)] = plant;
+        }
+    }
+
+    garden.to_string()
+}
@@ -0,0 +1,350 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use grid::{Dir, Grid, Pos};
+use render::{Animate, Animation, Cell, Color, Exploration, Explore, Frame, Recorder};
+
+pub use generate::generate;
+
+pub struct Data {
+    plots: Grid<char>,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let plots = Grid::parse(input, |pos, c| {
+            if c.is_ascii_uppercase() {
+                Ok(c)
+            } else {
+                Err(ParseError::new(
+                    pos.y + 1,
+                    pos.x + 1,
+                    c,
+                    "expected a plant type (A-Z)",
+                ))
+            }
+        })?;
+
+        Ok(Self { plots })
+    }
+
+    fn get(&self, pos: Option<Pos>) -> Option<&char> {
+        self.plots.get(pos?)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+// The regions show up one by one, in the colour of their plant
+impl Animate for Day {
+    fn animate(data: &Data, part: u8, animation: &mut Animation) {
+        calc(data, part == 2, animation);
+    }
+}
+
+// Steps through the regions like the animation, and tells the region of every plot
+impl Explore for Day {
+    fn explore(data: &Data) -> Exploration<'_> {
+        let (region_ids, regions) = find_regions(data);
+
+        let draw_ids = region_ids.clone();
+        let num_regions = regions.len();
+
+        Exploration::with_steps(num_regions, move |step| {
+            draw_map(data, &draw_ids.map(|_, id| *id <= step))
+                .with_caption(format!("{} of {num_regions} regions", step + 1))
+        })
+        .with_inspector(move |pos| {
+            let id = region_ids[pos];
+            let region = &regions[id];
+            format!(
+                "plant {}, region {id} with area {}, perimeter {} and {} sides",
+                data.plots[pos], region.area, region.perimeter, region.sides
+            )
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> isize {
+    calc(data, false, &mut ())
+}
+
+pub fn part_two(data: &Data) -> isize {
+    calc(data, true, &mut ())
+}
+
+fn calc(data: &Data, sections: bool, recorder: &mut impl Recorder) -> isize {
+    let mut fence_dirs = get_fence_dirs(data);
+    let mut is_part_of_region = data.plots.map(|_, _| false);
+
+    let mut sum = 0;
+
+    for pos in data.plots.positions() {
+        let (area, fence) =
+            get_score_for_region(pos, data, &mut fence_dirs, &mut is_part_of_region, sections);
+
+        sum += area * fence;
+
+        if area > 0 {
+            recorder
+                .record(|| draw_map(data, &is_part_of_region).with_caption(format!("price {sum}")));
+        }
+    }
+
+    sum
+}
+
+struct Region {
+    area: isize,
+    perimeter: isize,
+    sides: isize,
+}
+
+// Which region every plot belongs to, with the regions numbered in the order `calc` finds them
+fn find_regions(data: &Data) -> (Grid<usize>, Vec<Region>) {
+    let mut region_ids = data.plots.map(|_, _| usize::MAX);
+    let mut regions = vec![];
+
+    let mut perimeter_fences = get_fence_dirs(data);
+    let mut side_fences = get_fence_dirs(data);
+    let mut counted_perimeter = data.plots.map(|_, _| false);
+    let mut counted_sides = data.plots.map(|_, _| false);
+
+    for start in data.plots.positions() {
+        if region_ids[start] != usize::MAX {
+            continue;
+        }
+
+        let id = regions.len();
+        region_ids[start] = id;
+
+        let mut todo = vec![start];
+        while let Some(pos) = todo.pop() {
+            for next in data.plots.neighbours4(pos) {
+                if data.plots[next] == data.plots[pos] && region_ids[next] == usize::MAX {
+                    region_ids[next] = id;
+                    todo.push(next);
+                }
+            }
+        }
+
+        let (area, perimeter) = get_score_for_region(
+            start,
+            data,
+            &mut perimeter_fences,
+            &mut counted_perimeter,
+            false,
+        );
+        let (_, sides) =
+            get_score_for_region(start, data, &mut side_fences, &mut counted_sides, true);
+
+        regions.push(Region {
+            area,
+            perimeter,
+            sides,
+        });
+    }
+
+    (region_ids, regions)
+}
+
+// The plots of the regions found so far are coloured by their plant. Neighbouring regions
+// always have different plants, so they always get different colours.
+fn draw_map(data: &Data, is_part_of_region: &Grid<bool>) -> Frame {
+    Frame::new(&data.plots, |pos, plant| {
+        if is_part_of_region[pos] {
+            Cell::new(*plant).fg(Color::Black).bg(plant_color(*plant))
+        } else {
+            Cell::new(*plant).fg(Color::BrightBlack)
+        }
+    })
+}
+
+// 26 colours spread around the colour wheel, neighbouring letters get very different ones
+fn plant_color(plant: char) -> Color {
+    let index = plant as u32 - 'A' as u32;
+    let hue = (index * 7 % 26) as f64 / 26.0 * 6.0;
+
+    // Saturation 0.6 and value 0.9, the same for every plant
+    let (max, min) = (0.9, 0.9 * 0.4);
+    let rising = min + (max - min) * (hue % 1.0);
+    let falling = max - (max - min) * (hue % 1.0);
+
+    let (r, g, b) = match hue as u32 {
+        0 => (max, rising, min),
+        1 => (falling, max, min),
+        2 => (min, max, rising),
+        3 => (min, falling, max),
+        4 => (rising, min, max),
+        _ => (max, min, falling),
+    };
+
+    let channel = |value: f64| (value * 255.0).round() as u8;
+    Color::TrueColor {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+    }
+}
+
+// For every plot, the directions in which it needs a fence
+fn get_fence_dirs(data: &Data) -> Grid<Vec<Dir>> {
+    data.plots.map(|pos, c| {
+        Dir::cardinals()
+            .filter(|dir| {
+                if let Some(neighbor) = data.get(pos.checked_add(dir.offset())) {
+                    c != neighbor
+                } else {
+                    true
+                }
+            })
+            .collect()
+    })
+}
+
+fn get_score_for_region(
+    start_pos: Pos,
+    data: &Data,
+    fence_dirs: &mut Grid<Vec<Dir>>,
+    is_part_of_region: &mut Grid<bool>,
+    count_sections: bool,
+) -> (isize, isize) {
+    let Some(plant) = data.get(Some(start_pos)) else {
+        return (0, 0);
+    };
+
+    if is_part_of_region[start_pos] {
+        // We already were here
+        return (0, 0);
+    }
+
+    is_part_of_region[start_pos] = true;
+
+    let mut area_sum = 1;
+    let mut fence_sum: isize = 0;
+    if count_sections {
+        while let Some(dir) = fence_dirs[start_pos].pop() {
+            // Remove the fence from the neighbouring plots on both sides, as it is part of
+            // the same section
+            for side in [dir.turn_left(), dir.turn_right()] {
+                let mut side_pos = Some(start_pos);
+
+                while let Some(pos) = side_pos.filter(|pos| data.get(Some(*pos)) == Some(plant)) {
+                    let old_len = fence_dirs[pos].len();
+                    fence_dirs[pos].retain(|side_dir| *side_dir != dir);
+                    if old_len == fence_dirs[pos].len() && pos != start_pos {
+                        break;
+                    }
+
+                    side_pos = pos.checked_add(side.offset());
+                }
+            }
+
+            fence_sum += 1;
+        }
+    } else {
+        fence_sum = fence_sum.wrapping_add_unsigned(fence_dirs[start_pos].len());
+    }
+
+    for neighbor_pos in data.plots.neighbours4(start_pos) {
+        if data.plots[neighbor_pos] == *plant {
+            let (neighbor_area_sum, neightbor_fence_sum) = get_score_for_region(
+                neighbor_pos,
+                data,
+                fence_dirs,
+                is_part_of_region,
+                count_sections,
+            );
+            area_sum += neighbor_area_sum;
+            fence_sum += neightbor_fence_sum;
+        }
+    }
+
+    (area_sum, fence_sum)
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+    use grid::Grid;
+    use proptest::{
+        prelude::{prop, ProptestConfig, Strategy},
+        prop_assert, proptest,
+    };
+
+    use crate::{generate, get_fence_dirs, get_score_for_region, Data, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 1930, 1206);
+    }
+
+    #[test]
+    fn test_example_2() {
+        check_example::<Day>(include_str!("../example_2.txt"), 772, 436);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    fn rect_grid(max_len: usize) -> impl Strategy<Value = Grid<char>> {
+        ((0..max_len), (0..max_len)).prop_flat_map(|(height, width)| {
+            prop::collection::vec(prop::char::range('A', 'Z'), width * height).prop_map(
+                move |plots| Grid::from_fn(width, height, |pos| plots[pos.y * width + pos.x]),
+            )
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(100_000))]
+        #[test]
+        fn test_all_polygons_have_even_number_of_sides(plots in rect_grid(10)) {
+            let data = Data { plots };
+
+            let mut fence_counts = get_fence_dirs(&data);
+            let mut is_part_of_region = data.plots.map(|_, _| false);
+
+            for pos in data.plots.positions() {
+                let (area, fence) = get_score_for_region(
+                    pos,
+                    &data,
+                    &mut fence_counts,
+                    &mut is_part_of_region,
+                    true,
+                );
+
+                if area > 0 {
+                    prop_assert!(fence % 2 == 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 30);
+    }
+}
@@ -0,0 +1,36 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// `size` claw machines. Most prizes can be won with at most 100 presses of each button,
+// the others are moved a bit so they usually can't be won at all. The buttons never move
+// the claw in the same direction, so there is at most one way to win.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut machines = vec![];
+    while machines.len() < size {
+        let a: (i64, i64) = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let b: (i64, i64) = (rng.gen_range(10..100), rng.gen_range(10..100));
+
+        if a.0 * b.1 == a.1 * b.0 {
+            continue;
+        }
+
+        let (presses_a, presses_b) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+        let mut prize = (
+            a.0 * presses_a + b.0 * presses_b,
+            a.1 * presses_a + b.1 * presses_b,
+        );
+
+        if rng.gen_bool(0.3) {
+            prize.0 += rng.gen_range(1..10);
+        }
+
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    machines.join("\n")
+}
@@ -0,0 +1,308 @@
+mod generate;
+
+use common::{parse_at, Answer, ParseError, Solution};
+use log::{debug, trace};
+use serde::Deserialize;
+
+pub use generate::generate;
+
+pub struct Data {
+    machines: Vec<Machine>,
+    params: Params,
+}
+
+impl Data {
+    fn from_str(input: &str, params: Params) -> Result<Self, ParseError> {
+        let mut machines = Vec::new();
+        let re = Regex::new(r"Button A: (.*)\nButton B: (.*)\nPrize: (.*)").unwrap();
+
+        let mut last_end = 0;
+        for cap in re.captures_iter(input) {
+            check_only_whitespace(input, &input[last_end..cap.get(0).unwrap().start()])?;
+            last_end = cap.get(0).unwrap().end();
+
+            let button_a = parse_coordinates(input, &cap[1])?;
+            let button_b = parse_coordinates(input, &cap[2])?;
+            let prize = parse_coordinates(input, &cap[3])?;
+
+            machines.push(Machine {
+                button_a,
+                button_b,
+                prize,
+            });
+        }
+        check_only_whitespace(input, &input[last_end..])?;
+
+        Ok(Self { machines, params })
+    }
+}
+
+use std::ops::{Add, Mul, Sub};
+
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Coordinates {
+    x: i64,
+    y: i64,
+}
+
+impl Add<Coordinates> for Coordinates {
+    type Output = Coordinates;
+
+    fn add(self, rhs: Coordinates) -> Self::Output {
+        Coordinates {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Sub<Coordinates> for Coordinates {
+    type Output = Coordinates;
+
+    fn sub(self, rhs: Coordinates) -> Self::Output {
+        Coordinates {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Mul<i64> for Coordinates {
+    type Output = Coordinates;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Coordinates {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Machine {
+    button_a: Coordinates,
+    button_b: Coordinates,
+    prize: Coordinates,
+}
+
+// Anything between the machines that is not whitespace did not match the machine pattern
+fn check_only_whitespace(input: &str, between: &str) -> Result<(), ParseError> {
+    match between.trim_start().lines().next() {
+        Some(line) if !line.is_empty() => Err(ParseError::at(
+            input,
+            line,
+            "expected a machine (Button A, Button B and Prize lines)",
+        )),
+        _ => Ok(()),
+    }
+}
+
+// `text` is a slice of `input`, which is only needed to locate errors
+fn parse_coordinates(input: &str, text: &str) -> Result<Coordinates, ParseError> {
+    let re = Regex::new(r"X=*([+-]?\d+),?\s*Y=*([+-]?\d+)").unwrap();
+    let caps = re
+        .captures(text)
+        .ok_or_else(|| ParseError::at(input, text, "expected coordinates like X+94, Y+34"))?;
+    Ok(Coordinates {
+        x: parse_at(input, &caps[1])?,
+        y: parse_at(input, &caps[2])?,
+    })
+}
+
+// How far part two moves every prize, in both x and y
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part_two_offset: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_two_offset: 10000000000000,
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
+
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    Data::from_str(input, params)
+}
+
+pub fn part_one(data: &Data) -> i64 {
+    let mut cum_cost = 0;
+    for machine in &data.machines {
+        trace!("{machine:?}");
+        let Some(cost) = solve_bruteforce(
+            machine.button_a,
+            machine.button_b,
+            machine.prize,
+            std::cmp::min,
+        ) else {
+            debug!("no solution found for machine {machine:?}");
+            continue;
+        };
+        trace!("costs {cost} tokens");
+
+        cum_cost += cost
+    }
+
+    cum_cost
+}
+
+pub fn part_two(data: &Data) -> i64 {
+    let mut cum_cost = 0;
+    for machine in &data.machines {
+        trace!("{machine:?}");
+        let Some((a, b)) = solve(
+            machine.button_a,
+            machine.button_b,
+            machine.prize
+                + Coordinates {
+                    x: data.params.part_two_offset,
+                    y: data.params.part_two_offset,
+                },
+        ) else {
+            debug!("no solution found for machine {machine:?}");
+            continue;
+        };
+        trace!("pressing A {a} and B {b} times");
+
+        cum_cost += 3 * a + b;
+    }
+
+    cum_cost
+}
+
+fn solve(a_value: Coordinates, b_value: Coordinates, goal: Coordinates) -> Option<(i64, i64)> {
+    if goal.x == 0 && goal.y == 0 {
+        return Some((0, 0));
+    }
+
+    for a in 0..=9 {
+        for b in 0..=9 {
+            // Only look at the last digit
+            let dest = (a_value * a) + (b_value * b);
+            if dest.x % 10 == goal.x % 10 && dest.y % 10 == goal.y % 10 {
+                // This is a possible solution for the last digit
+                let new_goal = goal - dest;
+
+                assert!(new_goal.x % 10 == 0);
+                assert!(new_goal.y % 10 == 0);
+
+                if let Some((next_a, next_b)) = solve(
+                    a_value,
+                    b_value,
+                    Coordinates {
+                        x: new_goal.x / 10,
+                        y: new_goal.y / 10,
+                    },
+                ) {
+                    // Found A solution
+                    return Some((next_a * 10 + a, next_b * 10 + b));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// I JUST read that button presses are limited to 100 so we can just bruteforce it lol
+fn solve_bruteforce(
+    a_value: Coordinates,
+    b_value: Coordinates,
+    goal: Coordinates,
+    min_fn: impl Fn(i64, i64) -> i64,
+) -> Option<i64> {
+    let mut min = None;
+
+    for a in 0..=100 {
+        for b in 0..=100 {
+            let dest = (a_value * a) + (b_value * b);
+
+            if dest == goal {
+                let cost = a * 3 + b;
+
+                if let Some(old_min) = min {
+                    min = Some(min_fn(old_min, cost))
+                } else {
+                    min = Some(cost)
+                }
+            }
+        }
+    }
+
+    min
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_generated_inputs, check_part_one, check_personal_answers};
+    use proptest::{prop_assert_eq, prop_assume, proptest};
+
+    use crate::{generate, solve, solve_bruteforce, Coordinates, Day};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), 480);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 20);
+    }
+
+    proptest! {
+        // The prize is usually reachable with at most 100 presses of each button, which is all
+        // solve_bruteforce looks at, but can be moved off by a bit
+        #[test]
+        fn test_solve_agrees_with_bruteforce(
+            (a_x, a_y, b_x, b_y) in (1..100_i64, 1..100_i64, 1..100_i64, 1..100_i64),
+            (presses_a, presses_b) in (0..=100_i64, 0..=100_i64),
+            (nudge_x, nudge_y) in (-2..=2_i64, -2..=2_i64),
+        ) {
+            // With buttons that move the claw the same way there are several ways to win,
+            // and solve takes the first one it finds rather than the cheapest
+            prop_assume!(a_x * b_y != a_y * b_x);
+
+            let button_a = Coordinates { x: a_x, y: a_y };
+            let button_b = Coordinates { x: b_x, y: b_y };
+            let prize = button_a * presses_a
+                + button_b * presses_b
+                + Coordinates { x: nudge_x, y: nudge_y };
+
+            let expected = solve_bruteforce(button_a, button_b, prize, std::cmp::min);
+            let actual = solve(button_a, button_b, prize)
+                .filter(|(a, b)| *a <= 100 && *b <= 100)
+                .map(|(a, b)| 3 * a + b);
+
+            prop_assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::Params;
+
+// `size` robots in the room of the real puzzle (see `Params`), at random positions
+// and with random velocities
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let Params { width, height } = Params::default();
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let velocity_x = rng.gen_range(-(width as isize)..width as isize);
+        let velocity_y = rng.gen_range(-(height as isize)..height as isize);
+
+        input.push_str(&format!(
+            "p={},{} v={velocity_x},{velocity_y}\n",
+            rng.gen_range(0..width),
+            rng.gen_range(0..height),
+        ));
+    }
+
+    input
+}
@@ -0,0 +1,299 @@
+mod generate;
+
+use common::{parse_at, Answer, ParseError, Solution};
+use log::debug;
+use render::{Animate, Animation, Cell, Color, Frame, Recorder};
+use serde::Deserialize;
+use std::time::Duration;
+
+pub use generate::generate;
+
+#[derive(Debug, Clone)]
+pub struct Data {
+    width: usize,
+    height: usize,
+    robots: Vec<Robot>,
+}
+
+#[derive(Debug, Clone)]
+struct Robot {
+    pos: (isize, isize),
+    velocity: (isize, isize),
+}
+
+impl Data {
+    fn from_str(input: &str, params: Params) -> Result<Self, ParseError> {
+        let Params { width, height } = params;
+
+        let robots = input
+            .lines()
+            .map(|line| Robot::from_str(input, line, width, height))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            width,
+            height,
+            robots,
+        })
+    }
+}
+
+impl Robot {
+    // `line` is a slice of `input`, which is only needed to locate errors
+    fn from_str(input: &str, line: &str, width: usize, height: usize) -> Result<Self, ParseError> {
+        let (p, v) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(input, line, "expected a robot like p=0,4 v=3,-3"))?;
+
+        let pos = parse_pair(input, p, "p=")?;
+        let velocity = parse_pair(input, v, "v=")?;
+
+        if !(0..width as isize).contains(&pos.0) || !(0..height as isize).contains(&pos.1) {
+            return Err(ParseError::at(
+                input,
+                p,
+                format!("position is outside of the {width}x{height} area"),
+            ));
+        }
+
+        Ok(Self { pos, velocity })
+    }
+}
+
+fn parse_pair(input: &str, text: &str, prefix: &str) -> Result<(isize, isize), ParseError> {
+    let (x, y) = text
+        .strip_prefix(prefix)
+        .and_then(|pair| pair.split_once(','))
+        .ok_or_else(|| ParseError::at(input, text, format!("expected {prefix}x,y")))?;
+
+    Ok((parse_at(input, x)?, parse_at(input, y)?))
+}
+
+// The size of the room. The example uses an 11x7 one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            width: 101,
+            height: 103,
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
+
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+// One frame per second, until the 100 seconds of part one are over or the picture shows up
+impl Animate for Day {
+    fn animate(data: &Data, part: u8, animation: &mut Animation) {
+        match part {
+            1 => {
+                safety_factor_after(data, 100, animation);
+            }
+            _ => {
+                find_picture(data, animation);
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    Data::from_str(input, params)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    safety_factor_after(data, 100, &mut ())
+}
+
+fn safety_factor_after(data: &Data, seconds: u64, recorder: &mut impl Recorder) -> usize {
+    let data = &mut data.clone();
+
+    let mut safety_factor = simulate_bruteforce(data, Duration::ZERO);
+
+    for second in 1..=seconds {
+        safety_factor = simulate_bruteforce(data, Duration::from_secs(1));
+
+        recorder.record(|| draw_map(data).with_caption(format!("second {second}")));
+    }
+
+    safety_factor
+}
+
+pub fn part_two(data: &Data) -> Option<u64> {
+    find_picture(data, &mut ())
+}
+
+fn find_picture(data: &Data, recorder: &mut impl Recorder) -> Option<u64> {
+    let data = &mut data.clone();
+
+    let mut time_passed = Duration::from_secs(0);
+
+    // The robots are back at their starting positions after width * height seconds,
+    // so there is no point in looking any further
+    for _ in 0..(data.width * data.height) {
+        simulate_bruteforce(data, Duration::from_secs(1));
+
+        time_passed += Duration::from_secs(1);
+
+        let std_dev = calc_std_dev(data);
+
+        recorder.record(|| {
+            draw_map(data).with_caption(format!(
+                "second {}, standard deviation {std_dev:.1}",
+                time_passed.as_secs()
+            ))
+        });
+
+        if std_dev < 40.0 {
+            debug!(
+                "picture after {} seconds, standard deviation {std_dev:.1}:\n{}",
+                time_passed.as_secs(),
+                draw_map(data).plain()
+            );
+            return Some(time_passed.as_secs());
+        }
+    }
+
+    // The robots never form a picture
+    None
+}
+
+fn calc_std_dev(data: &Data) -> f64 {
+    let sum_pos = data
+        .robots
+        .iter()
+        .map(|r| r.pos)
+        .fold((0, 0), |(curr_x, curr_y), (x, y)| (curr_x + x, curr_y + y));
+
+    let avg_pos = (
+        sum_pos.0 as f64 / data.robots.len() as f64,
+        sum_pos.1 as f64 / data.robots.len() as f64,
+    );
+
+    (data
+        .robots
+        .iter()
+        .map(|r| r.pos)
+        .map(|(x, y)| ((x as f64 - avg_pos.0).abs() + (y as f64 - avg_pos.1).abs()).powi(2))
+        .sum::<f64>()
+        / data.robots.len() as f64)
+        .sqrt()
+}
+
+// The number of robots on every tile
+fn draw_map(data: &Data) -> Frame {
+    let mut counts = vec![0; data.width * data.height];
+
+    for robot in &data.robots {
+        counts[robot.pos.1 as usize * data.width + robot.pos.0 as usize] += 1;
+    }
+
+    Frame::from_fn(data.width, data.height, |pos| {
+        match counts[pos.y * data.width + pos.x] {
+            0 => Cell::new('.').fg(Color::BrightBlack),
+            count => Cell::new(char::from_digit(count, 10).unwrap_or('*')).fg(Color::Green),
+        }
+    })
+}
+
+fn simulate_bruteforce(data: &mut Data, time: Duration) -> usize {
+    for _ in 0..time.as_secs() {
+        for robot in &mut data.robots {
+            let new_pos_pre_mod = (
+                robot.pos.0 + robot.velocity.0,
+                robot.pos.1 + robot.velocity.1,
+            );
+
+            let new_pos = (
+                new_pos_pre_mod.0.rem_euclid(data.width as isize),
+                new_pos_pre_mod.1.rem_euclid(data.height as isize),
+            );
+
+            robot.pos = new_pos;
+        }
+    }
+
+    // Do the quadrant eval:
+    let mut vals = [[0, 0], [0, 0]];
+
+    for robot in &mut data.robots {
+        assert!(robot.pos.0 >= 0 && robot.pos.0 < data.width as isize);
+        assert!(robot.pos.1 >= 0 && robot.pos.1 < data.height as isize);
+
+        let x_quad = if robot.pos.0 < (data.width / 2) as isize {
+            0usize
+        } else if robot.pos.0 >= (data.width.div_ceil(2)) as isize {
+            1usize
+        } else {
+            // To reach this, the board must be odd in width!
+            assert_eq!(robot.pos.0, (data.width / 2) as isize);
+            continue;
+        };
+
+        let y_quad = if robot.pos.1 < (data.height / 2) as isize {
+            0usize
+        } else if robot.pos.1 >= (data.height.div_ceil(2)) as isize {
+            1usize
+        } else {
+            // To reach this, the board must be odd in height!
+            assert_eq!(robot.pos.1, (data.height / 2) as isize);
+            continue;
+        };
+
+        vals[y_quad][x_quad] += 1;
+    }
+
+    vals.iter().flatten().product()
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_generated_inputs, check_part_one_with, check_personal_answers};
+
+    use crate::{generate, Day, Params};
+
+    // Part two looks for a picture, which the example doesn't have
+    #[test]
+    fn test_example_part_one() {
+        check_part_one_with::<Day>(
+            include_str!("../example.txt"),
+            Params {
+                width: 11,
+                height: 7,
+            },
+            12,
+        );
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 50);
+    }
+}
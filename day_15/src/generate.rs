@@ -0,0 +1,39 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use grid::{Grid, Pos};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// A `size` x `size` warehouse surrounded by walls, with boxes and some more walls inside,
+// followed by `size` * `size` moves of the robot in lines of 1000 like the real puzzle
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    // The robot needs a tile inside the walls
+    let size = size.max(3);
+
+    let mut map = Grid::from_fn(size, size, |pos| {
+        if pos.x == 0 || pos.y == 0 || pos.x == size - 1 || pos.y == size - 1 {
+            '#'
+        } else {
+            match rng.gen_range(0..10) {
+                0 => '#',
+                1..=4 => 'O',
+                _ => '.',
+            }
+        }
+    });
+
+    let robot = Pos::new(rng.gen_range(1..size - 1), rng.gen_range(1..size - 1));
+    map[robot] = '@';
+
+    let moves: Vec<char> = (0..size * size)
+        .map(|_| *['^', '>', 'v', '<'].choose(&mut rng).unwrap())
+        .collect();
+
+    let mut input = map.to_string();
+    input.push('\n');
+    for line in moves.chunks(1000) {
+        input.extend(line);
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,345 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use grid::{Dir, Grid, Pos};
+use log::debug;
+use render::{Animate, Animation, Cell, Color, Frame, Recorder};
+
+pub use generate::generate;
+
+#[derive(Debug, Clone)]
+pub struct Data {
+    map: Grid<Tile>,
+    robot_pos: Pos,
+    instructions: Vec<Dir>,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (map, instructions) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(input, "expected an empty line between map and moves")
+        })?;
+
+        let mut player_pos = None;
+
+        let map_text = map;
+        let map = Grid::parse(map, |pos, c| match c {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Empty),
+            'O' => Ok(Tile::Box),
+            '@' if player_pos.is_some() => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "found a second robot",
+            )),
+            '@' => {
+                player_pos = Some(pos);
+                Ok(Tile::Empty)
+            }
+            // Wide boxes only exist after widening the map for part two
+            c => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "expected one of # . O @",
+            )),
+        })?;
+
+        // The robot and the boxes can't leave the warehouse
+        if let Some((pos, _)) = map.iter().find(|(pos, tile)| {
+            let on_border =
+                pos.x == 0 || pos.y == 0 || pos.x == map.width() - 1 || pos.y == map.height() - 1;
+            on_border && **tile != Tile::Wall
+        }) {
+            let c = map_text
+                .lines()
+                .nth(pos.y)
+                .and_then(|line| line.chars().nth(pos.x))
+                .unwrap_or_default();
+            return Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "expected a wall all around the warehouse",
+            ));
+        }
+
+        let instructions = instructions
+            .char_indices()
+            .filter_map(|(i, c)| match c {
+                '\n' => None,
+                c => Some(Dir::from_arrow(c).ok_or_else(|| {
+                    ParseError::at(
+                        input,
+                        &instructions[i..i + c.len_utf8()],
+                        "expected one of ^ > v <",
+                    )
+                })),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            map,
+            robot_pos: player_pos.ok_or_else(|| ParseError::at_end(input, "no robot found"))?,
+            instructions,
+        })
+    }
+
+    // Every tile of the map becomes twice as wide, for part two
+    fn widen(&self) -> Self {
+        let map = Grid::from_fn(self.map.width() * 2, self.map.height(), |pos| {
+            let halves = match self.map[Pos::new(pos.x / 2, pos.y)] {
+                Tile::Empty => [Tile::Empty, Tile::Empty],
+                Tile::Box => [Tile::LeftBoxHalf, Tile::RightBoxHalf],
+                Tile::Wall => [Tile::Wall, Tile::Wall],
+                Tile::LeftBoxHalf | Tile::RightBoxHalf => unreachable!("Long box in input"),
+            };
+
+            halves[pos.x % 2]
+        });
+
+        Self {
+            map,
+            robot_pos: Pos::new(self.robot_pos.x * 2, self.robot_pos.y),
+            instructions: self.instructions.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Empty,
+    Box,
+    Wall,
+    LeftBoxHalf,
+    RightBoxHalf,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+// One frame per move of the robot, in the normal or the widened warehouse
+impl Animate for Day {
+    fn animate(data: &Data, part: u8, animation: &mut Animation) {
+        let data = &mut match part {
+            1 => data.clone(),
+            _ => data.widen(),
+        };
+
+        animation.record(|| draw_map(&data.map, data.robot_pos));
+
+        move_till_stuck(data, animation);
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    let data = &mut data.clone();
+
+    move_till_stuck(data, &mut ());
+
+    debug!(
+        "final warehouse:\n{}",
+        draw_map(&data.map, data.robot_pos).plain()
+    );
+
+    calc_gps(data)
+}
+
+pub fn part_two(data: &Data) -> usize {
+    let data = &mut data.widen();
+
+    move_till_stuck(data, &mut ());
+
+    debug!(
+        "final warehouse:\n{}",
+        draw_map(&data.map, data.robot_pos).plain()
+    );
+
+    calc_gps(data)
+}
+
+fn calc_gps(data: &Data) -> usize {
+    data.map
+        .iter()
+        .filter(|(_, tile)| **tile == Tile::Box || **tile == Tile::LeftBoxHalf)
+        .map(|(pos, _)| 100 * pos.y + pos.x)
+        .sum()
+}
+
+fn draw_map(map: &Grid<Tile>, robot_pos: Pos) -> Frame {
+    Frame::new(map, |pos, tile| match tile {
+        Tile::Empty if pos == robot_pos => Cell::new('@').fg(Color::Red),
+        Tile::Empty => Cell::new('.').fg(Color::BrightBlack),
+        Tile::Box => Cell::new('O').fg(Color::Yellow),
+        Tile::Wall => Cell::new('#'),
+        Tile::LeftBoxHalf => Cell::new('[').fg(Color::Yellow),
+        Tile::RightBoxHalf => Cell::new(']').fg(Color::Yellow),
+    })
+}
+
+fn move_till_stuck(data: &mut Data, recorder: &mut impl Recorder) {
+    for (i, dir) in data.instructions.iter().enumerate() {
+        match dir {
+            Dir::U | Dir::R | Dir::D | Dir::L => {
+                let offs = dir.offset();
+                let front_pos = data.robot_pos.checked_add(offs).unwrap();
+
+                if try_move(&mut data.map, *dir, front_pos, false).is_ok() {
+                    assert_eq!(data.map[front_pos], Tile::Empty);
+
+                    data.robot_pos = front_pos;
+                }
+
+                recorder.record(|| {
+                    draw_map(&data.map, data.robot_pos).with_caption(format!(
+                        "move {} of {}: {}",
+                        i + 1,
+                        data.instructions.len(),
+                        dir.arrow().unwrap_or('?')
+                    ))
+                });
+            }
+            _ => unreachable!("Only cardinal directions allowed"),
+        }
+    }
+}
+
+fn try_move(map: &mut Grid<Tile>, dir: Dir, pos: Pos, dry_run: bool) -> Result<(), ()> {
+    match map[pos] {
+        Tile::Empty => Ok(()),
+        Tile::Box => {
+            let offs = dir.offset();
+            let front_pos = pos.checked_add(offs).unwrap();
+            if try_move(map, dir, front_pos, false).is_ok() {
+                assert_eq!(map[front_pos], Tile::Empty);
+                map[front_pos] = Tile::Box;
+                map[pos] = Tile::Empty;
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+        Tile::Wall => Err(()),
+        Tile::LeftBoxHalf => {
+            assert_eq!(map[Pos::new(pos.x + 1, pos.y)], Tile::RightBoxHalf);
+
+            let offs = dir.offset();
+            let front_pos = pos.checked_add(offs).unwrap();
+            if dir == Dir::U || dir == Dir::D {
+                if try_move(map, dir, front_pos, true).is_ok()
+                    && try_move(map, dir, Pos::new(front_pos.x + 1, front_pos.y), true).is_ok()
+                {
+                    if !dry_run {
+                        try_move(map, dir, front_pos, false).expect("We checked before");
+                        try_move(map, dir, Pos::new(front_pos.x + 1, front_pos.y), false)
+                            .expect("We checked before");
+
+                        //assert_eq!(map[front_pos], Tile::Empty);
+                        //assert_eq!(map[Pos::new(front_pos.x + 1, front_pos.y)], Tile::Empty);
+                        map[front_pos] = Tile::LeftBoxHalf;
+                        map[Pos::new(front_pos.x + 1, front_pos.y)] = Tile::RightBoxHalf;
+                        map[pos] = Tile::Empty;
+                        map[Pos::new(pos.x + 1, pos.y)] = Tile::Empty;
+                    }
+                    Ok(())
+                } else {
+                    Err(())
+                }
+            } else if try_move(map, dir, front_pos, false).is_ok() {
+                assert_eq!(map[front_pos], Tile::Empty);
+                map[front_pos] = Tile::LeftBoxHalf;
+                map[pos] = Tile::Empty;
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+        Tile::RightBoxHalf => {
+            assert_eq!(map[Pos::new(pos.x - 1, pos.y)], Tile::LeftBoxHalf);
+
+            let offs = dir.offset();
+            let front_pos = pos.checked_add(offs).unwrap();
+
+            if dir == Dir::U || dir == Dir::D {
+                if try_move(map, dir, front_pos, true).is_ok()
+                    && try_move(map, dir, Pos::new(front_pos.x - 1, front_pos.y), true).is_ok()
+                {
+                    if !dry_run {
+                        try_move(map, dir, front_pos, false).expect("We checked before");
+                        try_move(map, dir, Pos::new(front_pos.x - 1, front_pos.y), false)
+                            .expect("We checked before");
+
+                        assert_eq!(map[front_pos], Tile::Empty);
+                        assert_eq!(map[Pos::new(front_pos.x - 1, front_pos.y)], Tile::Empty);
+                        map[front_pos] = Tile::RightBoxHalf;
+                        map[Pos::new(front_pos.x - 1, front_pos.y)] = Tile::LeftBoxHalf;
+                        map[pos] = Tile::Empty;
+                        map[Pos::new(pos.x - 1, pos.y)] = Tile::Empty;
+                    }
+                    Ok(())
+                } else {
+                    Err(())
+                }
+            } else if try_move(map, dir, front_pos, false).is_ok() {
+                assert_eq!(map[front_pos], Tile::Empty);
+                map[front_pos] = Tile::RightBoxHalf;
+                map[pos] = Tile::Empty;
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, parse, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 10092, 9021);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 20);
+    }
+
+    #[test]
+    fn test_warehouse_without_walls() {
+        let err = parse("@\n\n<<<").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+
+        let err = parse("####\n#@O.\n####\n\n>>").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "."));
+    }
+}
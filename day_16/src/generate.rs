@@ -0,0 +1,51 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use grid::{Dir, Grid, Pos};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// A `size` x `size` maze (rounded up to an odd size) with the start in the bottom left and
+// the end in the top right corner. It starts as a perfect maze, then a few walls are knocked
+// out so there are several best paths, like in the real puzzle.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(5) | 1;
+
+    let mut maze = Grid::new(size, size, '#');
+    let start = Pos::new(1, size - 2);
+
+    // Carve a perfect maze with a depth first search over the tiles at odd coordinates
+    maze[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&pos) = stack.last() {
+        let mut dirs: Vec<Dir> = Dir::cardinals().collect();
+        dirs.shuffle(&mut rng);
+
+        let next = dirs.into_iter().find_map(|dir| {
+            let wall = maze.step(pos, dir.offset())?;
+            let next = maze.step(wall, dir.offset())?;
+            let inside = next.x > 0 && next.y > 0 && next.x < size - 1 && next.y < size - 1;
+
+            (inside && maze[next] == '#').then_some((wall, next))
+        });
+
+        match next {
+            Some((wall, next)) => {
+                maze[wall] = '.';
+                maze[next] = '.';
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    for _ in 0..size * size / 50 {
+        let pos = Pos::new(rng.gen_range(1..size - 1), rng.gen_range(1..size - 1));
+        maze[pos] = '.';
+    }
+
+    maze[start] = 'S';
+    maze[Pos::new(size - 2, 1)] = 'E';
+
+    maze.to_string()
+}
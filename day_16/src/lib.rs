@@ -0,0 +1,246 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use grid::{Dir, Grid, Pos};
+use itertools::Itertools;
+use log::debug;
+use petgraph::{
+    algo::astar,
+    prelude::{DiGraphMap, GraphMap},
+    visit::GraphBase,
+};
+
+pub use generate::generate;
+
+type MazeGraph = GraphMap<(Pos, Dir), i32, petgraph::Directed>;
+
+pub struct Data {
+    #[allow(dead_code)]
+    map: Grid<Tile>,
+    graph_info: GraphInfo<MazeGraph>,
+}
+
+struct GraphInfo<G: GraphBase> {
+    graph: G,
+    start: (Pos, Dir),
+    end: Pos,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (mut start, mut end) = (None, None);
+
+        let map = Grid::parse(input, |pos, c| match c {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Empty),
+            'S' if start.is_some() => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "found a second start",
+            )),
+            'E' if end.is_some() => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "found a second end",
+            )),
+            'S' => {
+                start = Some((pos, Dir::R));
+                Ok(Tile::Empty)
+            }
+            'E' => {
+                end = Some(pos);
+                Ok(Tile::Empty)
+            }
+            _ => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "expected one of # . S E",
+            )),
+        })?;
+
+        let mut graph = DiGraphMap::new();
+
+        for (pos, tile) in map.iter() {
+            if *tile == Tile::Wall {
+                continue;
+            }
+
+            for dir in Dir::cardinals() {
+                graph.add_edge((pos, dir), (pos, dir.turn_left()), 1000);
+                graph.add_edge((pos, dir), (pos, dir.turn_right()), 1000);
+
+                // Anything outside of the map counts as a wall
+                let Some(next_pos) = map.step(pos, dir.offset()) else {
+                    continue;
+                };
+
+                if map[next_pos] == Tile::Empty {
+                    graph.add_edge((pos, dir), (next_pos, dir), 1);
+                }
+            }
+        }
+
+        Ok(Self {
+            map,
+            graph_info: GraphInfo {
+                graph,
+                start: start.ok_or_else(|| ParseError::at_end(input, "no start (S) found"))?,
+                end: end.ok_or_else(|| ParseError::at_end(input, "no end (E) found"))?,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Empty,
+    Wall,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> i32 {
+    // println!("{:?}", data.graph_info.graph);
+
+    let Some((cost, _path)) = astar(
+        &data.graph_info.graph,
+        data.graph_info.start,
+        |(pos, _dir)| pos == data.graph_info.end,
+        |(_a, _b, v)| *v,
+        |(pos, _dir)| {
+            data.graph_info
+                .end
+                .manhattan_distance(pos)
+                .try_into()
+                .unwrap()
+        },
+    ) else {
+        panic!("No path found!!");
+    };
+
+    cost
+}
+
+pub fn part_two(data: &Data) -> usize {
+    let mut k = 1;
+
+    let mut all_same_k = 1;
+
+    let mut fine_grained = false;
+
+    loop {
+        debug!("looking at the {k} shortest paths");
+
+        let ret = pathfinding::directed::yen::yen(
+            &data.graph_info.start,
+            |node| {
+                let cloned = *node;
+                data.graph_info.graph.neighbors(*node).map(move |n| {
+                    let cost = if n.1 == cloned.1 {
+                        // We went straight
+                        1
+                    } else {
+                        1000
+                    };
+                    (n, cost)
+                })
+            },
+            |node| node.0 == data.graph_info.end,
+            k,
+        );
+
+        if ret.iter().all(|(_, cost)| *cost == ret[0].1) {
+            if fine_grained {
+                k += 1;
+            } else {
+                all_same_k = k;
+                k *= 2;
+            }
+        } else if fine_grained {
+            break;
+        } else {
+            k = all_same_k;
+            fine_grained = true;
+        }
+    }
+
+    // k is not just high enough to include paths with not min cost!
+    k -= 1;
+
+    let ret = pathfinding::directed::yen::yen(
+        &data.graph_info.start,
+        |node| {
+            let cloned = *node;
+            data.graph_info.graph.neighbors(*node).map(move |n| {
+                let cost = if n.1 == cloned.1 {
+                    // We went straight
+                    1
+                } else {
+                    1000
+                };
+                (n, cost)
+            })
+        },
+        |node| node.0 == data.graph_info.end,
+        k,
+    );
+
+    ret.iter()
+        .flat_map(|(path, _cost)| path.iter())
+        .map(|(pos, _dir)| pos)
+        .unique()
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_part};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 7036, 45);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_part::<Day>(env!("CARGO_MANIFEST_DIR"), 1);
+    }
+
+    // Takes minutes
+    #[test]
+    #[ignore]
+    fn test_personal_part_two() {
+        check_personal_part::<Day>(env!("CARGO_MANIFEST_DIR"), 2);
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 21);
+    }
+}
@@ -0,0 +1,34 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// A program in the shape all real inputs have: a loop that mixes the lowest three bits of A
+// with some higher bits of A, outputs the result and shifts A right by three bits, until A is 0.
+// The constants and the order of the instructions in the loop are random.
+// Register A starts with `size` three bit digits, so part one outputs `size` values.
+// Unlike in the real puzzle, part two doesn't always have an answer for these.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // bst A; bxl k; cdv B
+    let mut program = vec![2, 4, 1, rng.gen_range(0..8), 7, 5];
+
+    // bxl k and bxc C in either order, with adv 3 anywhere in between
+    let mut middle = vec![[1, rng.gen_range(0..8)], [4, rng.gen_range(0..8)]];
+    middle.shuffle(&mut rng);
+    middle.insert(rng.gen_range(0..=middle.len()), [0, 3]);
+    program.extend(middle.into_iter().flatten());
+
+    // out B; jnz 0
+    program.extend([5, 5, 3, 0]);
+
+    // A has to be at least 1 for the loop to run
+    let digits = size.clamp(1, 21) as u32;
+    let a: u64 = rng.gen_range(8_u64.pow(digits - 1)..8_u64.pow(digits));
+
+    let program: Vec<String> = program.iter().map(|value| value.to_string()).collect();
+
+    format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        program.join(",")
+    )
+}
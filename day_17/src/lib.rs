@@ -0,0 +1,451 @@
+mod generate;
+
+use common::{parse_at, Answer, NoParams, ParseError, Solution};
+use log::{debug, trace};
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use itertools::Itertools;
+
+pub use generate::generate;
+
+#[derive(Debug, Clone)]
+pub struct Data {
+    inital_state: State,
+    program: Vec<Instruction>,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    ip: usize,
+
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq)]
+enum Instruction {
+    ADV(ComboOperand),
+    BXL(LiteralOperand),
+    BST(ComboOperand),
+    JNZ(LiteralOperand),
+    BXC(LiteralOperand),
+    OUT(ComboOperand),
+    BDV(ComboOperand),
+    CDV(ComboOperand),
+}
+
+impl Instruction {
+    fn try_from_values(values: (u8, u8)) -> Option<Self> {
+        match values.0 {
+            0 => Some(Self::ADV(values.1.try_into().ok()?)),
+            1 => Some(Self::BXL(values.1.try_into().ok()?)),
+            2 => Some(Self::BST(values.1.try_into().ok()?)),
+            3 => Some(Self::JNZ(values.1.try_into().ok()?)),
+            4 => Some(Self::BXC(values.1.try_into().ok()?)),
+            5 => Some(Self::OUT(values.1.try_into().ok()?)),
+            6 => Some(Self::BDV(values.1.try_into().ok()?)),
+            7 => Some(Self::CDV(values.1.try_into().ok()?)),
+            _ => None,
+        }
+    }
+
+    fn bytecode(&self) -> [u8; 2] {
+        match self {
+            Instruction::ADV(op) => (0, op.0),
+            Instruction::BXL(op) => (1, op.0),
+            Instruction::BST(op) => (2, op.0),
+            Instruction::JNZ(op) => (3, op.0),
+            Instruction::BXC(op) => (4, op.0),
+            Instruction::OUT(op) => (5, op.0),
+            Instruction::BDV(op) => (6, op.0),
+            Instruction::CDV(op) => (7, op.0),
+        }
+        .into()
+    }
+}
+
+trait Operand {
+    fn evaluate(&self, state: &State) -> u64;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LiteralOperand(u8);
+
+impl TryFrom<u8> for LiteralOperand {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 7 {
+            Err(())
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl Operand for LiteralOperand {
+    fn evaluate(&self, _state: &State) -> u64 {
+        self.0.into()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ComboOperand(u8);
+
+impl TryFrom<u8> for ComboOperand {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if value > 6 {
+            Err(())
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl Operand for ComboOperand {
+    fn evaluate(&self, state: &State) -> u64 {
+        match self.0 {
+            0..=3 => self.0.into(),
+            4 => state.a,
+            5 => state.b,
+            6 => state.c,
+            _ => unreachable!("Invalid ComboOperand"),
+        }
+    }
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (state, instructions) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(
+                input,
+                "expected an empty line between registers and program",
+            )
+        })?;
+
+        let mut registers = state.lines();
+        let mut next_register = |name| {
+            let line = registers
+                .next()
+                .ok_or_else(|| ParseError::at(input, state, format!("missing register {name}")))?;
+
+            let value = line
+                .strip_prefix(&format!("Register {name}:"))
+                .ok_or_else(|| {
+                    ParseError::at(input, line, format!("expected Register {name}: <value>"))
+                })?;
+
+            parse_at(input, value.trim())
+        };
+
+        let a = next_register('A')?;
+        let b = next_register('B')?;
+        let c = next_register('C')?;
+
+        let inital_state = State { ip: 0, a, b, c };
+
+        let values = instructions
+            .trim()
+            .strip_prefix("Program:")
+            .ok_or_else(|| ParseError::at(input, instructions, "expected Program: <values>"))?
+            .trim()
+            .split(',')
+            .map(|s| Ok((s, parse_at::<u8>(input, s)?)))
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        if values.len() % 2 != 0 {
+            return Err(ParseError::at_end(
+                input,
+                "the last instruction is missing its operand",
+            ));
+        }
+
+        let program = values
+            .chunks(2)
+            .map(|chunk| {
+                let (opcode_str, opcode) = chunk[0];
+                let (operand_str, operand) = chunk[1];
+
+                let instruction = Instruction::try_from_values((opcode, operand))
+                    .ok_or_else(|| ParseError::at(input, opcode_str, "not a valid instruction"))?;
+
+                // The interpreter works on whole instructions, so it can not jump into the middle of one
+                if let Instruction::JNZ(op) = &instruction {
+                    if op.0 % 2 != 0 {
+                        return Err(ParseError::at(
+                            input,
+                            operand_str,
+                            "jump target must be even",
+                        ));
+                    }
+                }
+
+                Ok(instruction)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            inital_state,
+            program,
+        })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> String {
+    let mut output = Vec::new();
+
+    let mut state = data.inital_state.clone();
+
+    while let Some(instruction) = data.program.get(state.ip) {
+        if let Some(out) = execute_instruction(instruction, &mut state) {
+            output.push(out);
+        }
+    }
+
+    output.iter().join(",")
+}
+
+pub fn part_two_bruteforce(data: &Data) -> Option<u64> {
+    // I just let the bruteforce run while i wrote the better solution lol
+    // const STARTVALUE: u64 = 10_586_306_000_000;
+    const STARTVALUE: u64 = 212928664708;
+
+    let goal: Vec<u8> = data.program.iter().flat_map(|i| i.bytecode()).collect();
+
+    bruteforce_a(data, &goal, STARTVALUE)
+}
+
+// Smallest value of A from `start_value` on for which the program outputs `goal`
+fn bruteforce_a(data: &Data, goal: &[u8], start_value: u64) -> Option<u64> {
+    const NUM_THREADS: u64 = 10;
+
+    // Every thread keeps going until it passes the best value found so far,
+    // so a smaller value in another thread is never missed
+    let best = AtomicU64::new(u64::MAX);
+
+    thread::scope(|scope| {
+        for thread_idx in 0..NUM_THREADS {
+            let best = &best;
+            scope.spawn(move || {
+                let mut a_value = start_value + thread_idx;
+
+                while a_value < best.load(Ordering::Relaxed) {
+                    if thread_idx == 0 && a_value % 1_000_000 == 0 {
+                        debug!("tried A values up to {a_value}");
+                    }
+
+                    let mut state = data.inital_state.clone();
+
+                    state.a = a_value;
+
+                    let mut out_index = 0;
+                    let mut found_diff = false;
+
+                    while let Some(instruction) = data.program.get(state.ip) {
+                        if let Some(out) = execute_instruction(instruction, &mut state) {
+                            if goal.get(out_index) != Some(&out) {
+                                found_diff = true;
+                                break;
+                            }
+
+                            out_index += 1;
+                        }
+                    }
+
+                    if found_diff || out_index != goal.len() {
+                        a_value += NUM_THREADS;
+                    } else {
+                        best.fetch_min(a_value, Ordering::Relaxed);
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    Some(best.into_inner()).filter(|&a| a != u64::MAX)
+}
+
+fn execute_instruction(instruction: &Instruction, state: &mut State) -> Option<u8> {
+    state.ip += 1;
+
+    match instruction {
+        Instruction::ADV(op) => state.a /= 2_u64.pow(op.evaluate(state).try_into().unwrap()),
+        Instruction::BDV(op) => {
+            state.b = state.a / 2_u64.pow(op.evaluate(state).try_into().unwrap())
+        }
+        Instruction::CDV(op) => {
+            state.c = state.a / 2_u64.pow(op.evaluate(state).try_into().unwrap())
+        }
+
+        Instruction::BXL(op) => state.b ^= op.evaluate(state),
+        Instruction::BST(op) => state.b = op.evaluate(state) % 8,
+        Instruction::JNZ(op) => {
+            if state.a > 0 {
+                // my current implementation does not work if we jump to an odd address!
+                assert!(op.evaluate(state) % 2 == 0);
+                state.ip = usize::try_from(op.evaluate(state)).unwrap() / 2;
+            }
+        }
+        Instruction::BXC(_op) => state.b ^= state.c,
+        Instruction::OUT(op) => return Some((op.evaluate(state) % 8).try_into().unwrap()),
+    }
+
+    None
+}
+
+pub fn part_two(data: &Data) -> Option<u64> {
+    let goal: Vec<u8> = data.program.iter().flat_map(|i| i.bytecode()).collect();
+
+    solve(&data.inital_state, &data.program, &goal, 0, goal.len() - 1)
+}
+
+fn solve(
+    initial_state: &State,
+    program: &[Instruction],
+    goal: &[u8],
+    a: u64,
+    index: usize,
+) -> Option<u64> {
+    // try 3 bits at a time
+    for i in 0..8 {
+        let new_a = (a << 3) | i;
+
+        if new_a == 0 && index > 0 {
+            // A leading zero would end the loop one output too early
+            continue;
+        }
+
+        let mut state = initial_state.clone();
+        state.a = new_a;
+
+        while let Some(instruction) = program.get(state.ip) {
+            if let Some(out) = execute_instruction(instruction, &mut state) {
+                if out == goal[index] {
+                    if index == 0 {
+                        return Some(new_a);
+                    }
+
+                    // Next value
+                    if let Some(val) = solve(initial_state, program, goal, new_a, index - 1) {
+                        return Some(val);
+                    }
+                }
+
+                break;
+            }
+        }
+    }
+
+    trace!("backtracking");
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{
+        check_generated_inputs, check_part_one, check_part_two, check_personal_answers,
+    };
+
+    use itertools::Itertools;
+    use proptest::{
+        prelude::{prop, Strategy},
+        prop_assert_eq, proptest,
+    };
+
+    use crate::{bruteforce_a, generate, parse, part_one, solve, Day};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_example_part_two() {
+        check_part_two::<Day>(include_str!("../example_2.txt"), 117440);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 8);
+    }
+
+    // Programs in the shape of the real inputs (see generate.rs) with a small value in register A
+    fn program() -> impl Strategy<Value = String> {
+        (
+            0..8_u8,
+            0..8_u8,
+            0..8_u8,
+            prop::bool::ANY,
+            0..3_usize,
+            1..8_u64.pow(5),
+        )
+            .prop_map(|(k1, k2, k3, swap, adv_index, a)| {
+                let mut middle = vec![[1, k2], [4, k3]];
+                if swap {
+                    middle.reverse();
+                }
+                middle.insert(adv_index, [0, 3]);
+
+                let program = [2, 4, 1, k1, 7, 5]
+                    .into_iter()
+                    .chain(middle.into_iter().flatten())
+                    .chain([5, 5, 3, 0])
+                    .join(",");
+
+                format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n")
+            })
+    }
+
+    proptest! {
+        // Instead of looking for the program itself, both look for whatever the program
+        // printed, so there always is an answer
+        #[test]
+        fn test_solve_agrees_with_bruteforce(input in program()) {
+            let data = parse(&input).unwrap();
+            let goal: Vec<u8> = part_one(&data)
+                .split(',')
+                .map(|value| value.parse().unwrap())
+                .collect();
+
+            let expected = bruteforce_a(&data, &goal, 0);
+            prop_assert_eq!(solve(&data.inital_state, &data.program, &goal, 0, goal.len() - 1), expected);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use grid::{Dir, Grid, Pos};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+// Bytes falling into a `size` x `size` memory space (rounded up to an odd size). The real
+// puzzle uses 71, any other size has to be passed as the `size` parameter too.
+// Under the bytes there is a random path from the top left to the bottom right corner, and
+// bytes only start to fall onto it once everything around it is full, so the way out stays
+// open for as long as possible.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(3) | 1;
+
+    let exit = Pos::new(size - 1, size - 1);
+    let parents = random_spanning_tree(size, &mut rng);
+
+    let mut on_path = Grid::new(size, size, false);
+    let mut pos = exit;
+    on_path[pos] = true;
+    while let Some((wall, parent)) = parents[pos] {
+        on_path[wall] = true;
+        on_path[parent] = true;
+        pos = parent;
+    }
+
+    let mut off_path: Vec<Pos> = on_path.positions().filter(|pos| !on_path[*pos]).collect();
+    let mut path: Vec<Pos> = on_path
+        .positions()
+        .filter(|pos| on_path[*pos] && *pos != Pos::new(0, 0) && *pos != exit)
+        .collect();
+    off_path.shuffle(&mut rng);
+    path.shuffle(&mut rng);
+
+    let mut input = String::new();
+    for Pos { x, y } in off_path.into_iter().chain(path) {
+        input.push_str(&format!("{x},{y}\n"));
+    }
+
+    input
+}
+
+// A depth first search over the tiles at even coordinates, starting from the top left.
+// For every tile it finds, this holds the wall it came through and the tile it came from.
+fn random_spanning_tree(size: usize, rng: &mut StdRng) -> Grid<Option<(Pos, Pos)>> {
+    let mut parents = Grid::new(size, size, None);
+    let mut visited = Grid::new(size, size, false);
+
+    let start = Pos::new(0, 0);
+    visited[start] = true;
+    let mut stack = vec![start];
+    while let Some(&pos) = stack.last() {
+        let mut dirs: Vec<Dir> = Dir::cardinals().collect();
+        dirs.shuffle(rng);
+
+        let next = dirs.into_iter().find_map(|dir| {
+            let wall = visited.step(pos, dir.offset())?;
+            let next = visited.step(wall, dir.offset())?;
+
+            (!visited[next]).then_some((wall, next))
+        });
+
+        match next {
+            Some((wall, next)) => {
+                visited[next] = true;
+                parents[next] = Some((wall, pos));
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    parents
+}
@@ -0,0 +1,286 @@
+mod generate;
+
+use common::{parse_at, Answer, ParseError, Solution};
+use grid::{Grid, Pos};
+use log::{debug, trace};
+use render::{Animate, Animation, Cell, Color, Exploration, Explore, Frame, Recorder};
+use serde::Deserialize;
+
+pub use generate::generate;
+
+#[derive(Debug)]
+pub struct Data {
+    bytes: Vec<Pos>,
+    params: Params,
+}
+
+impl Data {
+    fn from_str(input: &str, params: Params) -> Result<Self, ParseError> {
+        let size = params.size;
+
+        let bytes = input
+            .lines()
+            .map(|line| {
+                let (x, y) = line
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::at(input, line, "expected a position like 5,4"))?;
+
+                let pos = Pos::new(parse_at(input, x)?, parse_at(input, y)?);
+
+                if pos.x >= size || pos.y >= size {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("position is outside of the {size}x{size} memory space"),
+                    ));
+                }
+
+                Ok(pos)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { bytes, params })
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl Map {
+    fn from_data(data: &Data, size: usize) -> Self {
+        let mut tiles = Grid::new(size, size, Tile::PermanentlyFree);
+
+        for (time, pos) in data.bytes.iter().enumerate() {
+            if tiles[*pos] == Tile::PermanentlyFree {
+                // The first byte (idx 0) falls after 1 ns
+                tiles[*pos] = Tile::CorruptedAt(time + 1);
+            }
+        }
+
+        Self { tiles }
+    }
+
+    fn exit(&self) -> Pos {
+        Pos::new(self.tiles.width() - 1, self.tiles.height() - 1)
+    }
+
+    // The free tiles next to `pos` at the given time
+    fn successors(&self, pos: Pos, time: usize) -> impl Iterator<Item = Pos> + '_ {
+        self.tiles
+            .neighbours4(pos)
+            .filter(move |next| match self.tiles[*next] {
+                Tile::PermanentlyFree => true,
+                Tile::CorruptedAt(time_corrupted) => time < time_corrupted,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    PermanentlyFree,
+    CorruptedAt(usize),
+}
+
+// The memory space is `size` x `size` (7x7 in the example), and part one looks at it
+// once `fallen_bytes` bytes have fallen (12 in the example)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub size: usize,
+    pub fallen_bytes: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            size: 71,
+            fallen_bytes: 1024,
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
+
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+// Part one lets the bytes fall and then finds the way out, part two keeps the bytes falling
+// until there is no way out any more
+impl Animate for Day {
+    fn animate(data: &Data, part: u8, animation: &mut Animation) {
+        match part {
+            1 => {
+                shortest_path(data, animation);
+            }
+            _ => {
+                first_blocking_byte(data, animation);
+            }
+        }
+    }
+}
+
+// One step per fallen byte, each with the shortest way out at that time
+impl Explore for Day {
+    fn explore(data: &Data) -> Exploration<'_> {
+        let map = Map::from_data(data, data.params.size);
+        let part_one_path = find_path(&map, data.params.fallen_bytes).unwrap_or_default();
+        let blocking_byte = first_blocking_byte(data, &mut ());
+
+        Exploration::with_steps(data.bytes.len() + 1, move |time| {
+            let path = find_path(&map, time);
+            let caption = match &path {
+                Some(path) => format!("{time} bytes fell, {} steps to the exit", path.len() - 1),
+                None => format!("{time} bytes fell, there is no way out"),
+            };
+
+            draw_map(&map, time, path.unwrap_or_default()).with_caption(caption)
+        })
+        .with_overlay("path of part one", Color::Blue, part_one_path)
+        .with_overlay("first blocking byte", Color::Magenta, [blocking_byte])
+        .with_inspector(
+            |pos| match data.bytes.iter().position(|byte| *byte == pos) {
+                Some(index) => format!("byte {} falls here after {} ns", index + 1, index + 1),
+                None => "no byte falls here".to_string(),
+            },
+        )
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    Data::from_str(input, params)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    shortest_path(data, &mut ())
+}
+
+fn shortest_path(data: &Data, recorder: &mut impl Recorder) -> usize {
+    let map = Map::from_data(data, data.params.size);
+
+    for time in 0..data.params.fallen_bytes {
+        recorder.record(|| draw_map(&map, time, []).with_caption(format!("{time} bytes fell")));
+    }
+
+    let path = find_path(&map, data.params.fallen_bytes).unwrap();
+
+    let num_tiles = path.len();
+
+    let num_steps = num_tiles - 1;
+
+    let path_frame = || draw_map(&map, data.params.fallen_bytes, path.iter().copied());
+
+    recorder.record(|| path_frame().with_caption(format!("{num_steps} steps to the exit")));
+
+    debug!("shortest path:\n{}", path_frame().plain());
+
+    num_steps
+}
+
+pub fn part_two(data: &Data) -> String {
+    let Pos { x, y } = first_blocking_byte(data, &mut ());
+
+    format!("{x},{y}")
+}
+
+fn first_blocking_byte(data: &Data, recorder: &mut impl Recorder) -> Pos {
+    let map = Map::from_data(data, data.params.size);
+
+    let mut current_time = data.params.fallen_bytes;
+
+    loop {
+        let path = find_path(&map, current_time);
+
+        trace!("{current_time} bytes fallen");
+
+        recorder.record(|| {
+            draw_map(&map, current_time, path.iter().flatten().copied())
+                .with_caption(format!("{current_time} bytes fell"))
+        });
+
+        match path {
+            Some(_) => current_time += 1,
+            None => break,
+        }
+    }
+
+    data.bytes[current_time - 1]
+}
+
+// The shortest way from the top left to the exit once `time` bytes fell, if there is one
+fn find_path(map: &Map, time: usize) -> Option<Vec<Pos>> {
+    const TIME_PER_MOVE: usize = 0;
+
+    let (path, _) = pathfinding::directed::astar::astar(
+        &(Pos::new(0, 0), time),
+        |(pos, time)| {
+            let time = *time + TIME_PER_MOVE;
+
+            map.successors(*pos, time)
+                .map(move |next| ((next, time), 1))
+        },
+        |(pos, _time)| pos.x * pos.x + pos.y * pos.y,
+        |(pos, _time)| *pos == map.exit(),
+    )?;
+
+    Some(path.into_iter().map(|(pos, _time)| pos).collect())
+}
+
+// The memory space after `time_step` bytes fell, with a path through it
+fn draw_map(map: &Map, time_step: usize, path: impl IntoIterator<Item = Pos>) -> Frame {
+    Frame::new(&map.tiles, |_, tile| match tile {
+        Tile::CorruptedAt(time_corrupted) if *time_corrupted <= time_step => {
+            Cell::new('#').fg(Color::Red)
+        }
+        Tile::PermanentlyFree | Tile::CorruptedAt(_) => Cell::new('.').fg(Color::BrightBlack),
+    })
+    .overlay(path, |cell| {
+        assert_ne!(cell.ch, '#', "the path goes through a corrupted byte");
+        Cell::new('O').fg(Color::Green)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example_with, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day, Params};
+
+    #[test]
+    fn test_example() {
+        check_example_with::<Day>(
+            include_str!("../example.txt"),
+            Params {
+                size: 7,
+                fallen_bytes: 12,
+            },
+            22,
+            "6,1",
+        );
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 71);
+    }
+}
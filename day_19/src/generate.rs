@@ -0,0 +1,41 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+// 50 to 450 towel patterns and `size` designs. About half of the designs are glued together
+// from the patterns, the others are random and usually impossible to make.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let num_patterns = rng.gen_range(50..450);
+    let patterns: Vec<String> = (0..num_patterns)
+        .map(|_| random_colors(&mut rng, 1..=8))
+        .collect();
+
+    let mut input = patterns.join(", ");
+    input.push_str("\n\n");
+
+    for _ in 0..size {
+        let design = if rng.gen_bool(0.5) {
+            let mut design = String::new();
+            while design.len() < 40 {
+                design.push_str(patterns.choose(&mut rng).unwrap());
+            }
+            design
+        } else {
+            random_colors(&mut rng, 20..=60)
+        };
+
+        input.push_str(&design);
+        input.push('\n');
+    }
+
+    input
+}
+
+fn random_colors(rng: &mut StdRng, len: std::ops::RangeInclusive<usize>) -> String {
+    (0..rng.gen_range(len))
+        .map(|_| *COLORS.choose(rng).unwrap())
+        .collect()
+}
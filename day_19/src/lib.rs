@@ -0,0 +1,165 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use std::collections::HashMap;
+
+pub use generate::generate;
+
+#[derive(Debug)]
+pub struct Data {
+    available_patterns: Vec<Vec<Color>>,
+
+    requested_designs: Vec<Vec<Color>>,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (patterns, designs) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(input, "expected an empty line between patterns and designs")
+        })?;
+
+        let mut available_patterns: Vec<Vec<Color>> = patterns
+            .split(',')
+            .map(|s| s.trim())
+            .map(|s| {
+                if s.is_empty() {
+                    Err(ParseError::at(input, s, "expected a towel pattern"))
+                } else {
+                    parse_colors(input, s)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        available_patterns.sort_by_key(|a| a.len());
+
+        let requested_designs = designs
+            .lines()
+            .map(|line| parse_colors(input, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            available_patterns,
+            requested_designs,
+        })
+    }
+}
+
+// `text` is a slice of `input`, which is only needed to locate errors
+fn parse_colors(input: &str, text: &str) -> Result<Vec<Color>, ParseError> {
+    text.char_indices()
+        .map(|(i, c)| match c {
+            'w' | 'u' | 'b' | 'r' | 'g' => Ok(Color(c)),
+            _ => Err(ParseError::at(
+                input,
+                &text[i..i + c.len_utf8()],
+                "expected a stripe color (w, u, b, r or g)",
+            )),
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone, Copy)]
+struct Color(char);
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    data.requested_designs
+        .iter()
+        .filter(|design| greedy(&data.available_patterns, design).is_some())
+        .count()
+}
+
+pub fn part_two(data: &Data) -> u64 {
+    let mut map = HashMap::new();
+
+    data.requested_designs
+        .iter()
+        .map(|design| greedy_all(&data.available_patterns, design, &mut map))
+        .sum()
+}
+
+fn greedy(available_patterns: &[Vec<Color>], goal: &[Color]) -> Option<Vec<usize>> {
+    if goal.is_empty() {
+        return Some(vec![]);
+    }
+
+    for (i, pattern) in available_patterns.iter().enumerate() {
+        if let Some(rest) = goal.strip_prefix(pattern.as_slice()) {
+            if let Some(mut ret) = greedy(available_patterns, rest) {
+                ret.push(i);
+                return Some(ret);
+            }
+        }
+    }
+
+    None
+}
+
+fn greedy_all(
+    available_patterns: &[Vec<Color>],
+    goal: &[Color],
+    lookup: &mut HashMap<Vec<Color>, u64>,
+) -> u64 {
+    if goal.is_empty() {
+        return 1;
+    }
+    if let Some(val) = lookup.get(goal) {
+        return *val;
+    }
+
+    let mut cum = 0;
+
+    for pattern in available_patterns.iter() {
+        if let Some(rest) = goal.strip_prefix(pattern.as_slice()) {
+            let ret = greedy_all(available_patterns, rest, lookup);
+            cum += ret;
+        }
+    }
+
+    lookup.insert(goal.to_vec(), cum);
+    cum
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 6, 16);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 20);
+    }
+}
@@ -0,0 +1,32 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// `size` reports of 5 to 8 levels. Most of them change gradually, some have one or two
+// bad levels in them, so all three answers (safe, safe with the dampener, unsafe) show up.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.gen_range(5..=8);
+        let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+
+        let mut level: i64 = rng.gen_range(20..80);
+        let mut levels = vec![];
+        for _ in 0..len {
+            levels.push(level);
+            level += direction * rng.gen_range(1..=3);
+        }
+
+        for _ in 0..rng.gen_range(0..=2) {
+            let idx = rng.gen_range(0..len);
+            levels[idx] += rng.gen_range(-5..=5);
+        }
+
+        let line: Vec<String> = levels.iter().map(|level| level.to_string()).collect();
+        input.push_str(&line.join(" "));
+        input.push('\n');
+    }
+
+    input
+}
@@ -0,0 +1,546 @@
+mod generate;
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+use common::{parse_at, Answer, ParseError, Solution};
+use log::{debug, log_enabled, trace, Level};
+use serde::Deserialize;
+
+pub use generate::generate;
+
+#[derive(Debug)]
+pub struct Data {
+    reports: Vec<Vec<u32>>,
+    params: Params,
+}
+
+// How many levels the Problem Dampener of part two may remove from a report, one in the
+// puzzle, and what makes a report safe. The rules are a table of their own:
+//
+//   max_removals = 2
+//
+//   [rules]
+//   max_step = 5
+//
+// or `--param rules.max_step=5` on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub max_removals: usize,
+    pub rules: Rules,
+    // Print to stderr how many levels part two has to remove from each report at least
+    pub print_removals: bool,
+    // Print the `Diagnosis` part two makes of every report that isn't safe as it is to stderr
+    pub diagnose: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            max_removals: 1,
+            rules: Rules::default(),
+            print_removals: false,
+            diagnose: false,
+        }
+    }
+}
+
+// What the levels of a safe report may do from one to the next. The puzzle wants steps of 1
+// to 3, all up or all down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules {
+    pub min_step: u32,
+    pub max_step: u32,
+    // Whether a level may be the same as the one before, whatever `min_step` says
+    pub allow_plateaus: bool,
+    // Whether all steps have to go the same way, plateaus don't count
+    pub require_direction: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            allow_plateaus: false,
+            require_direction: true,
+        }
+    }
+}
+
+impl Rules {
+    // Whether going from `last_level` to `level` is fine. `dir` is the way the report goes,
+    // None if it doesn't have to go any particular way (yet).
+    fn check_step(self, last_level: u32, level: u32, dir: Option<Ordering>) -> Result<(), Reason> {
+        let step = last_level.abs_diff(level);
+
+        if step == 0 {
+            return if self.allow_plateaus {
+                Ok(())
+            } else {
+                Err(Reason::StepTooSmall)
+            };
+        }
+
+        if dir.is_some_and(|dir| last_level.cmp(&level) != dir) {
+            return Err(Reason::DirectionChange);
+        }
+
+        if step < self.min_step {
+            return Err(Reason::StepTooSmall);
+        }
+
+        if step > self.max_step {
+            return Err(Reason::StepTooLarge);
+        }
+
+        Ok(())
+    }
+
+    // Up and down if the reports have to go one way, otherwise any way at all
+    fn directions(self) -> &'static [Option<Ordering>] {
+        if self.require_direction {
+            &[Some(Ordering::Less), Some(Ordering::Greater)]
+        } else {
+            &[None]
+        }
+    }
+
+    // Where a report first breaks the rules, None if it is safe
+    pub fn check(self, report: &[u32]) -> Option<Violation> {
+        let mut dir = None;
+
+        for (index, pair) in report.windows(2).enumerate() {
+            let (last_level, level) = (pair[0], pair[1]);
+
+            if let Err(reason) = self.check_step(last_level, level, dir) {
+                return Some(Violation {
+                    index: index + 1,
+                    reason,
+                });
+            }
+
+            // The first step that isn't a plateau decides the way
+            if self.require_direction && last_level != level {
+                dir = dir.or(Some(last_level.cmp(&level)));
+            }
+        }
+
+        None
+    }
+}
+
+// Why a step of a report is unsafe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    DirectionChange,
+    StepTooSmall,
+    StepTooLarge,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::DirectionChange => write!(f, "the direction changes"),
+            Reason::StepTooSmall => write!(f, "the step is too small"),
+            Reason::StepTooLarge => write!(f, "the step is too large"),
+        }
+    }
+}
+
+// The first level of a report that breaks the rules, counted from 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub reason: Reason,
+}
+
+// Everything there is to say about the safety of a report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    // None for a report that is safe as it is
+    pub violation: Option<Violation>,
+    // The indices of the fewest levels the dampener removes to make the report safe, None if
+    // that takes more than `max_removals`
+    pub removed: Option<Vec<usize>>,
+    pub max_removals: usize,
+}
+
+impl Diagnosis {
+    pub fn is_safe(&self) -> bool {
+        self.removed.is_some()
+    }
+}
+
+// Like "unsafe at level 3, the step is too large, safe without level 2". The levels are
+// counted from 1, like the columns of the input.
+impl Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(violation) = self.violation else {
+            return write!(f, "safe");
+        };
+
+        write!(
+            f,
+            "unsafe at level {}, {}",
+            violation.index + 1,
+            violation.reason
+        )?;
+
+        match &self.removed {
+            Some(removed) => {
+                let levels: Vec<String> = removed
+                    .iter()
+                    .map(|index| (index + 1).to_string())
+                    .collect();
+                let noun = if removed.len() == 1 {
+                    "level"
+                } else {
+                    "levels"
+                };
+
+                write!(f, ", safe without {noun} {}", levels.join(", "))
+            }
+            None if self.max_removals == 0 => Ok(()),
+            None => write!(f, ", and the dampener can't make it safe"),
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
+
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    let reports = input
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected a report, found an empty line",
+                ));
+            }
+
+            line.split_whitespace()
+                .map(|level| parse_at(input, level))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Data { reports, params })
+}
+
+pub fn part_one(data: &Data) -> usize {
+    count_safe(&diagnose_reports(data, 0))
+}
+
+pub fn part_two(data: &Data) -> usize {
+    let diagnoses = diagnose_reports(data, data.params.max_removals);
+
+    if log_enabled!(Level::Trace) {
+        for (idx, diagnosis) in diagnoses.iter().enumerate() {
+            trace!("report {}: {}", idx + 1, fewest_removals(diagnosis));
+        }
+    }
+
+    if data.params.diagnose || data.params.print_removals {
+        print_explanation(&data.params, &diagnoses);
+    }
+
+    count_safe(&diagnoses)
+}
+
+// With at most `max_removals` levels removed, logging the reports that aren't safe as they are
+fn diagnose_reports(data: &Data, max_removals: usize) -> Vec<Diagnosis> {
+    let diagnoses: Vec<Diagnosis> = data
+        .reports
+        .iter()
+        .map(|report| diagnose(report, data.params.rules, max_removals))
+        .collect();
+
+    for (idx, diagnosis) in diagnoses.iter().enumerate() {
+        if diagnosis.violation.is_some() {
+            debug!("report {}: {diagnosis}", idx + 1);
+        }
+    }
+
+    debug!(
+        "{} safe reports with max_removals = {max_removals}",
+        count_safe(&diagnoses)
+    );
+
+    diagnoses
+}
+
+fn count_safe(diagnoses: &[Diagnosis]) -> usize {
+    diagnoses
+        .iter()
+        .filter(|diagnosis| diagnosis.is_safe())
+        .count()
+}
+
+fn fewest_removals(diagnosis: &Diagnosis) -> String {
+    match &diagnosis.removed {
+        Some(removed) => format!("fewest removals {}", removed.len()),
+        None => format!("more than {} removals", diagnosis.max_removals),
+    }
+}
+
+// What `diagnose` and `print_removals` ask for, for the diagnoses of part two. This is the only
+// place that prints anything but the answers, the rest goes to the log.
+fn print_explanation(params: &Params, diagnoses: &[Diagnosis]) {
+    for (idx, diagnosis) in diagnoses.iter().enumerate() {
+        if params.print_removals {
+            eprintln!("report {}: {}", idx + 1, fewest_removals(diagnosis));
+        }
+
+        if params.diagnose && diagnosis.violation.is_some() {
+            eprintln!("report {}: {diagnosis}", idx + 1);
+        }
+    }
+}
+
+pub fn diagnose(report: &[u32], rules: Rules, max_removals: usize) -> Diagnosis {
+    Diagnosis {
+        violation: rules.check(report),
+        removed: dampen(report, rules, max_removals),
+        max_removals,
+    }
+}
+
+// The fewest levels each report needs removed to be safe, None for those that need more than
+// `max_removals`. Any number of removals would make this quadratic in the length of a report.
+pub fn removals_needed(data: &Data) -> Vec<Option<usize>> {
+    data.reports
+        .iter()
+        .map(|report| min_removals(report, data.params.rules, data.params.max_removals))
+        .collect()
+}
+
+// The fewest levels that have to be removed to make the report safe, None if that is more
+// than `max_removals`
+pub fn min_removals(report: &[u32], rules: Rules, max_removals: usize) -> Option<usize> {
+    dampen(report, rules, max_removals).map(|removed| removed.len())
+}
+
+// The indices of the fewest levels that have to be removed to make the report safe, None if
+// that is more than `max_removals`
+pub fn dampen(report: &[u32], rules: Rules, max_removals: usize) -> Option<Vec<usize>> {
+    rules
+        .directions()
+        .iter()
+        .filter_map(|dir| dampen_in_dir(report, rules, max_removals, *dir))
+        .min_by_key(|removed| removed.len())
+}
+
+// Goes through the levels once, keeping for each one the fewest removals among the levels
+// up to it that leave it in a safe report, and the level kept before it. With at most
+// `max_removals` removals there are at most that many levels between two that are kept, so
+// only the last `max_removals + 1` levels have to be looked at, and this is linear for a
+// fixed `max_removals`.
+fn dampen_in_dir(
+    report: &[u32],
+    rules: Rules,
+    max_removals: usize,
+    dir: Option<Ordering>,
+) -> Option<Vec<usize>> {
+    if report.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut fewest: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(report.len());
+
+    for (idx, level) in report.iter().enumerate() {
+        let kept_before = (idx.saturating_sub(max_removals.saturating_add(1))..idx)
+            .filter(|before| rules.check_step(report[*before], *level, dir).is_ok())
+            .filter_map(|before| {
+                let (removals, _) = fewest[before]?;
+                Some((removals + (idx - before - 1), Some(before)))
+            });
+
+        // Or the first level kept, with all before it removed
+        let first_kept = (idx, None);
+
+        let best = kept_before
+            .chain([first_kept])
+            .min_by_key(|(removals, _)| *removals)
+            .filter(|(removals, _)| *removals <= max_removals);
+
+        fewest.push(best);
+    }
+
+    // Everything after the last level kept is removed
+    let (_, last_kept) = fewest
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, best)| Some((best.as_ref()?.0 + (report.len() - 1 - idx), idx)))
+        .filter(|(removals, _)| *removals <= max_removals)
+        .min_by_key(|(removals, _)| *removals)?;
+
+    let mut kept = vec![false; report.len()];
+    let mut next = Some(last_kept);
+    while let Some(idx) = next {
+        kept[idx] = true;
+        next = fewest[idx].and_then(|(_, before)| before);
+    }
+
+    Some((0..report.len()).filter(|idx| !kept[*idx]).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{
+        check_example, check_generated_inputs, check_part_one_with, check_part_two_with,
+        check_personal_answers,
+    };
+
+    use crate::{
+        diagnose, generate, min_removals, parse, removals_needed, Day, Params, Reason, Rules,
+        Violation,
+    };
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 2, 4);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 100);
+    }
+
+    #[test]
+    fn test_more_removals() {
+        let input = include_str!("../example.txt");
+
+        check_part_two_with::<Day>(
+            input,
+            Params {
+                max_removals: 2,
+                ..Params::default()
+            },
+            6,
+        );
+
+        let data = parse(input, Params::default()).unwrap();
+        assert_eq!(
+            removals_needed(&data),
+            [Some(0), None, None, Some(1), Some(1), Some(0)]
+        );
+        let data = parse(
+            input,
+            Params {
+                max_removals: 2,
+                ..Params::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(removals_needed(&data), [0, 2, 2, 1, 1, 0].map(Some));
+
+        assert_eq!(
+            min_removals(&[1, 9, 2, 3, 8, 7, 4], Rules::default(), 3),
+            Some(3)
+        );
+        assert_eq!(
+            min_removals(&[1, 9, 2, 3, 8, 7, 4], Rules::default(), 2),
+            None
+        );
+        assert_eq!(
+            min_removals(&[1, 9, 2, 3, 8, 7, 4], Rules::default(), usize::MAX),
+            Some(3)
+        );
+        assert_eq!(min_removals(&[5, 1, 2, 3], Rules::default(), 0), None);
+        assert_eq!(min_removals(&[5, 1, 2, 3], Rules::default(), 1), Some(1));
+    }
+
+    #[test]
+    fn test_rules() {
+        let input = include_str!("../example.txt");
+        let with_rules = |rules| Params {
+            rules,
+            ..Params::default()
+        };
+
+        let larger_steps = Rules {
+            max_step: 5,
+            ..Rules::default()
+        };
+        check_part_one_with::<Day>(input, with_rules(larger_steps), 4);
+
+        let plateaus = Rules {
+            allow_plateaus: true,
+            ..Rules::default()
+        };
+        check_part_one_with::<Day>(input, with_rules(plateaus), 3);
+
+        let any_direction = Rules {
+            require_direction: false,
+            ..Rules::default()
+        };
+        check_part_one_with::<Day>(input, with_rules(any_direction), 3);
+    }
+
+    #[test]
+    fn test_diagnosis() {
+        let rules = Rules::default();
+
+        let diagnosis = diagnose(&[1, 2, 7, 8, 9], rules, 1);
+        assert_eq!(
+            diagnosis.violation,
+            Some(Violation {
+                index: 2,
+                reason: Reason::StepTooLarge
+            })
+        );
+        assert_eq!(diagnosis.removed, None);
+        assert_eq!(
+            diagnosis.to_string(),
+            "unsafe at level 3, the step is too large, and the dampener can't make it safe"
+        );
+
+        let diagnosis = diagnose(&[1, 3, 2, 4, 5], rules, 1);
+        assert_eq!(diagnosis.removed, Some(vec![2]));
+        assert_eq!(
+            diagnosis.to_string(),
+            "unsafe at level 3, the direction changes, safe without level 3"
+        );
+
+        assert_eq!(
+            diagnose(&[1, 2, 7, 8, 9], rules, 2).removed,
+            Some(vec![0, 1])
+        );
+        assert_eq!(diagnose(&[7, 6, 4, 2, 1], rules, 0).to_string(), "safe");
+        assert_eq!(
+            rules
+                .check(&[8, 6, 4, 4, 1])
+                .map(|violation| violation.reason),
+            Some(Reason::StepTooSmall)
+        );
+    }
+}
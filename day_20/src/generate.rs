@@ -0,0 +1,64 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use grid::{Dir, Grid, Pos};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// A `size` x `size` racetrack (rounded up to an odd size). Like in the real puzzle there is
+// exactly one path from start to end, winding through the walls.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(5) | 1;
+
+    let inside = |pos: Pos| pos.x > 0 && pos.y > 0 && pos.x < size - 1 && pos.y < size - 1;
+
+    // A depth first search over the tiles at odd coordinates, remembering where it came from
+    let start = Pos::new(
+        rng.gen_range(0..size / 2) * 2 + 1,
+        rng.gen_range(0..size / 2) * 2 + 1,
+    );
+    let mut parents: Grid<Option<(Pos, Pos)>> = Grid::new(size, size, None);
+    let mut visited = Grid::new(size, size, false);
+    visited[start] = true;
+
+    // The end is where the search got deepest, which makes for a long track
+    let mut end = (start, 0);
+    let mut stack = vec![start];
+    while let Some(&pos) = stack.last() {
+        let mut dirs: Vec<Dir> = Dir::cardinals().collect();
+        dirs.shuffle(&mut rng);
+
+        let next = dirs.into_iter().find_map(|dir| {
+            let wall = visited.step(pos, dir.offset())?;
+            let next = visited.step(wall, dir.offset())?;
+
+            (inside(next) && !visited[next]).then_some((wall, next))
+        });
+
+        match next {
+            Some((wall, next)) => {
+                visited[next] = true;
+                parents[next] = Some((wall, pos));
+                stack.push(next);
+
+                if stack.len() > end.1 {
+                    end = (next, stack.len());
+                }
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // Only the way from the start to the end becomes track, the rest of the search is walls
+    let mut track = Grid::new(size, size, '#');
+    let mut pos = end.0;
+    track[pos] = 'E';
+    while let Some((wall, parent)) = parents[pos] {
+        track[wall] = '.';
+        track[parent] = '.';
+        pos = parent;
+    }
+    track[start] = 'S';
+
+    track.to_string()
+}
@@ -0,0 +1,320 @@
+mod generate;
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Pos};
+use render::{Cell, Color, Exploration, Explore, Frame};
+use serde::Deserialize;
+
+pub use generate::generate;
+
+#[derive(Debug, Clone)]
+pub struct Data {
+    tiles: Grid<Tile>,
+    start_pos: Pos,
+    end_pos: Pos,
+    params: Params,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Track,
+    Wall,
+}
+
+impl Data {
+    fn from_str(input: &str, params: Params) -> Result<Self, ParseError> {
+        let mut start = None;
+        let mut end = None;
+
+        let tiles = Grid::parse(input, |pos, c| match c {
+            '.' => Ok(Tile::Track),
+            'S' if start.is_some() => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "found a second start",
+            )),
+            'E' if end.is_some() => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "found a second end",
+            )),
+            'S' => {
+                start = Some(pos);
+                Ok(Tile::Track)
+            }
+            'E' => {
+                end = Some(pos);
+                Ok(Tile::Track)
+            }
+            '#' => Ok(Tile::Wall),
+            _ => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "expected one of . # S E",
+            )),
+        })?;
+
+        let data = Self {
+            tiles,
+            start_pos: start.ok_or_else(|| ParseError::at_end(input, "no start (S) found"))?,
+            end_pos: end.ok_or_else(|| ParseError::at_end(input, "no end (E) found"))?,
+            params,
+        };
+
+        let reachable = pathfinding::directed::bfs::bfs_reach(data.start_pos, |pos| {
+            data.successors(*pos).collect::<Vec<_>>()
+        })
+        .any(|pos| pos == data.end_pos);
+        if !reachable {
+            return Err(ParseError::at_end(input, "no track leads from S to E"));
+        }
+
+        Ok(data)
+    }
+
+    // The track tiles next to `pos`
+    fn successors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.tiles
+            .neighbours4(pos)
+            .filter(|next| self.tiles[*next] == Tile::Track)
+    }
+}
+
+// Which cheats count: they have to save at least `min_saved_time` picoseconds and may pass
+// through walls for up to 2 (part one) or 20 (part two) picoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub min_saved_time: usize,
+    pub part_one_cheat_length: usize,
+    pub part_two_cheat_length: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            min_saved_time: 100,
+            part_one_cheat_length: 2,
+            part_two_cheat_length: 20,
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
+
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+impl Explore for Day {
+    fn explore(data: &Data) -> Exploration<'_> {
+        let base_path = race_track(data);
+
+        let map = Frame::new(&data.tiles, |pos, tile| match tile {
+            _ if pos == data.start_pos => Cell::new('S').fg(Color::Green),
+            _ if pos == data.end_pos => Cell::new('E').fg(Color::Red),
+            Tile::Track => Cell::new('.'),
+            Tile::Wall => Cell::new('#').fg(Color::BrightBlack),
+        });
+
+        let cheat_starts: Vec<Pos> = (0..base_path.len())
+            .filter(|idx| {
+                count_cheats_from(
+                    data,
+                    &base_path,
+                    *idx,
+                    data.params.part_one_cheat_length,
+                    is_reachable_in_exactly_n_steps,
+                ) > 0
+            })
+            .map(|idx| base_path[idx])
+            .collect();
+
+        Exploration::new(map)
+            .with_overlay("race track", Color::Blue, base_path.clone())
+            .with_overlay("cheat starts of part one", Color::Magenta, cheat_starts)
+            .with_inspector(move |pos| {
+                let Some(idx) = base_path.iter().position(|track| *track == pos) else {
+                    return "wall".to_string();
+                };
+
+                format!(
+                    "track, {idx} ps from the start and {} ps to the end, \
+                     {} cheats of part one and {} of part two start here",
+                    base_path.len() - 1 - idx,
+                    count_cheats_from(
+                        data,
+                        &base_path,
+                        idx,
+                        data.params.part_one_cheat_length,
+                        is_reachable_in_exactly_n_steps,
+                    ),
+                    count_cheats_from(
+                        data,
+                        &base_path,
+                        idx,
+                        data.params.part_two_cheat_length,
+                        is_reachable_in_max_n_steps,
+                    ),
+                )
+            })
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    Data::from_str(input, params)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    let base_path = race_track(data);
+
+    (0..base_path.len())
+        .map(|idx| {
+            count_cheats_from(
+                data,
+                &base_path,
+                idx,
+                data.params.part_one_cheat_length,
+                is_reachable_in_exactly_n_steps,
+            )
+        })
+        .sum()
+}
+
+pub fn part_two(data: &Data) -> usize {
+    let base_path = race_track(data);
+
+    (0..base_path.len())
+        .map(|idx| {
+            count_cheats_from(
+                data,
+                &base_path,
+                idx,
+                data.params.part_two_cheat_length,
+                is_reachable_in_max_n_steps,
+            )
+        })
+        .sum()
+}
+
+// The way from the start to the end without cheating, the track has no branches
+fn race_track(data: &Data) -> Vec<Pos> {
+    let (base_path, _) = pathfinding::directed::astar::astar(
+        &data.start_pos,
+        |pos| data.successors(*pos).map(|next| (next, 1usize)),
+        |pos| {
+            pos.x.abs_diff(data.start_pos.x) * pos.x.abs_diff(data.start_pos.x)
+                + pos.y.abs_diff(data.start_pos.y) * pos.y.abs_diff(data.start_pos.y)
+        },
+        |pos| *pos == data.end_pos,
+    )
+    .expect("Bad input");
+
+    base_path
+}
+
+// The cheats starting at `base_path[idx]` that save enough time
+fn count_cheats_from(
+    data: &Data,
+    base_path: &[Pos],
+    idx: usize,
+    cheat_length: usize,
+    is_reachable: fn(Pos, usize, Pos) -> Option<usize>,
+) -> usize {
+    let start_pos = base_path[idx];
+
+    base_path[idx..]
+        .iter()
+        .skip(data.params.min_saved_time)
+        .enumerate()
+        .filter_map(|(num_skipped, cheat_end_pos)| {
+            is_reachable(start_pos, cheat_length, *cheat_end_pos).map(|v| (v, num_skipped))
+        })
+        .filter(|(num_cheat_steps, num_normal_steps_skipped)| {
+            num_cheat_steps <= num_normal_steps_skipped
+        })
+        .count()
+}
+
+fn is_reachable_in_exactly_n_steps(start: Pos, n: usize, end: Pos) -> Option<usize> {
+    if start.manhattan_distance(end) == n {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+fn is_reachable_in_max_n_steps(start: Pos, n: usize, end: Pos) -> Option<usize> {
+    if start.manhattan_distance(end) <= n {
+        Some(start.manhattan_distance(end))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{
+        check_example_with, check_generated_inputs, check_part_two_with, check_personal_answers,
+    };
+
+    use crate::{generate, parse, Day, Params};
+
+    // The example has no cheats saving 100 picoseconds, the puzzle text counts smaller savings
+    #[test]
+    fn test_example() {
+        check_example_with::<Day>(
+            include_str!("../example.txt"),
+            Params {
+                min_saved_time: 50,
+                ..Params::default()
+            },
+            1,
+            285,
+        );
+    }
+
+    #[test]
+    fn test_example_part_two_at_least_76() {
+        check_part_two_with::<Day>(
+            include_str!("../example.txt"),
+            Params {
+                min_saved_time: 76,
+                ..Params::default()
+            },
+            3,
+        );
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 41);
+    }
+
+    #[test]
+    fn test_no_track() {
+        assert!(parse("#S#E#", Params::default()).is_err());
+        assert!(parse("#####\n#S.E#\n#####", Params::default()).is_ok());
+    }
+}
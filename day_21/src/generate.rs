@@ -0,0 +1,14 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// `size` door codes of three digits followed by an A
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    for _ in 0..size {
+        input.push_str(&format!("{:03}A\n", rng.gen_range(0..1000)));
+    }
+
+    input
+}
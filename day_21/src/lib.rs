@@ -1,77 +1,42 @@
-use std::{collections::HashMap, fs::File, io::Read};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-enum Dir {
-    U,
-    R,
-    D,
-    L,
-}
-
-impl Dir {
-    fn into_offsets(self) -> (isize, isize) {
-        let (y, x) = match self {
-            Dir::U => (-1, 0),
-            Dir::R => (0, 1),
-            Dir::D => (1, 0),
-            Dir::L => (0, -1),
-        };
+mod generate;
 
-        (x, y)
-    }
+use common::{parse_at, Answer, ParseError, Solution};
+use grid::Dir;
+use serde::Deserialize;
+use std::collections::HashMap;
 
-    fn get_opposite(self) -> Self {
-        match self {
-            Dir::U => Dir::D,
-            Dir::R => Dir::L,
-            Dir::D => Dir::U,
-            Dir::L => Dir::R,
-        }
-    }
+pub use generate::generate;
 
-    fn turn_right(self) -> Self {
-        match self {
-            Dir::U => Dir::R,
-            Dir::R => Dir::D,
-            Dir::D => Dir::L,
-            Dir::L => Dir::U,
-        }
-    }
-
-    fn turn_left(self) -> Self {
-        self.turn_right().get_opposite()
-    }
-}
-
-struct Data {
+pub struct Data {
     codes: Vec<(Vec<KeyVal>, usize)>,
+    params: Params,
 }
 
 impl Data {
-    fn from_str(input: &str) -> Self {
+    fn from_str(input: &str, params: Params) -> Result<Self, ParseError> {
         let codes = input
             .lines()
             .map(|line| {
-                (
-                    line.chars()
-                        .map(|c| match c {
-                            c if c.is_numeric() => {
-                                KeyVal::Num(c.to_digit(10).unwrap().try_into().unwrap())
-                            }
-                            'A' => KeyVal::A,
-                            _ => unreachable!(),
-                        })
-                        .collect(),
-                    line.split('A')
-                        .next()
-                        .expect("No seqment found")
-                        .parse()
-                        .expect("Could not parse into usize"),
-                )
+                let keys = line
+                    .char_indices()
+                    .map(|(i, c)| match c {
+                        '0'..='9' => Ok(KeyVal::Num(c as u8 - b'0')),
+                        'A' => Ok(KeyVal::A),
+                        _ => Err(ParseError::at(
+                            input,
+                            &line[i..i + c.len_utf8()],
+                            "expected a keypad key (0-9 or A)",
+                        )),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                let numeric_part = line.split('A').next().unwrap_or(line);
+
+                Ok((keys, parse_at(input, numeric_part)?))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        Self { codes }
+        Ok(Self { codes, params })
     }
 }
 
@@ -147,68 +112,61 @@ impl DirVal {
             DirVal::Dir(Dir::L) => (0, 1),
             DirVal::Dir(Dir::D) => (1, 1),
             DirVal::Dir(Dir::R) => (2, 1),
+            DirVal::Dir(dir) => unreachable!("There is no key for {dir:?}"),
         }
     }
 }
 
-fn main() {
-    let mut input = String::new();
-
-    File::open("./input.txt")
-        .expect("Could not open File")
-        .read_to_string(&mut input)
-        .expect("Could not read File");
-
-    let data = Data::from_str(&input);
+// How many directional keypads are operated by robots between you and the numeric keypad
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part_one_robots: usize,
+    pub part_two_robots: usize,
+}
 
-    part_two(&data);
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_one_robots: 2,
+            part_two_robots: 25,
+        }
+    }
 }
 
-fn part_one(data: &Data) {
-    let mut lookup_dirs = HashMap::new();
+pub struct Day;
 
-    for i in 0..=4 {
-        let start_key = match i {
-            0 => DirVal::A,
-            1 => DirVal::Dir(Dir::U),
-            2 => DirVal::Dir(Dir::R),
-            3 => DirVal::Dir(Dir::D),
-            4 => DirVal::Dir(Dir::L),
-            _ => unreachable!(),
-        };
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
 
-        for i in 0..=4 {
-            let end_key = match i {
-                0 => DirVal::A,
-                1 => DirVal::Dir(Dir::U),
-                2 => DirVal::Dir(Dir::R),
-                3 => DirVal::Dir(Dir::D),
-                4 => DirVal::Dir(Dir::L),
-                _ => unreachable!(),
-            };
-
-            lookup_dirs.insert((start_key, end_key, 0), 1);
-        }
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
     }
 
-    for num_robots in 1..=2 {
-        optimize_dirpad(num_robots, &mut lookup_dirs);
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
     }
 
-    let lookup_keys = optimize_keypad(&lookup_dirs, 3);
-
-    let mut sum = 0;
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
 
-    for (code, prefix) in &data.codes {
-        let solution = solve_with_lookups(code, &lookup_keys, 3);
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    Data::from_str(input, params)
+}
 
-        sum += solution * prefix;
-    }
+pub fn part_one(data: &Data) -> usize {
+    sum_of_complexities(data, data.params.part_one_robots)
+}
 
-    dbg!(sum);
+pub fn part_two(data: &Data) -> usize {
+    sum_of_complexities(data, data.params.part_two_robots)
 }
 
-fn part_two(data: &Data) {
+// The sum of the complexities of all codes, with `num_robots` robots on directional keypads
+fn sum_of_complexities(data: &Data, num_robots: usize) -> usize {
     let mut lookup_dirs = HashMap::new();
 
     for i in 0..=4 {
@@ -235,21 +193,21 @@ fn part_two(data: &Data) {
         }
     }
 
-    for num_robots in 1..=25 {
-        optimize_dirpad(num_robots, &mut lookup_dirs);
+    for robot in 1..=num_robots {
+        optimize_dirpad(robot, &mut lookup_dirs);
     }
 
-    let lookup_keys = optimize_keypad(&lookup_dirs, 26);
+    let lookup_keys = optimize_keypad(&lookup_dirs, num_robots + 1);
 
     let mut sum = 0;
 
     for (code, prefix) in &data.codes {
-        let solution = solve_with_lookups(code, &lookup_keys, 26);
+        let solution = solve_with_lookups(code, &lookup_keys, num_robots + 1);
 
         sum += solution * prefix;
     }
 
-    dbg!(sum);
+    sum
 }
 
 fn solve_with_lookups(
@@ -370,8 +328,8 @@ fn cost_to_press_from_to(
 
             for dir in &directions {
                 match (
-                    pos.0.checked_add_signed(dir.into_offsets().0),
-                    pos.1.checked_add_signed(dir.into_offsets().1),
+                    pos.0.checked_add_signed(dir.dx()),
+                    pos.1.checked_add_signed(dir.dy()),
                 ) {
                     (None, None) => continue,
                     (None, Some(_)) => continue,
@@ -419,11 +377,11 @@ fn cost_to_press_from_to(
                 break 'current_len;
             }
 
-            for i in 0..current_len {
-                if directions[i] == Dir::L {
-                    directions[i] = Dir::U;
+            for direction in directions.iter_mut() {
+                if *direction == Dir::L {
+                    *direction = Dir::U;
                 } else {
-                    directions[i] = directions[i].turn_right();
+                    *direction = direction.turn_right();
                     break;
                 }
             }
@@ -438,3 +396,25 @@ fn cost_to_press_from_to(
 
     Some(current_best_len)
 }
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_generated_inputs, check_part_one, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), 126384);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 5);
+    }
+}
@@ -0,0 +1,14 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// The initial secret numbers of `size` buyers
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    for _ in 0..size {
+        input.push_str(&format!("{}\n", rng.gen_range(1..16777216)));
+    }
+
+    input
+}
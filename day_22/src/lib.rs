@@ -1,59 +1,101 @@
-use std::{cmp::max, fs::File, io::Read};
+mod generate;
 
+use common::{parse_at, Answer, ParseError, Solution};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use serde::Deserialize;
 
+pub use generate::generate;
+
+#[allow(clippy::upper_case_acronyms)]
 type RNG = i64;
 
-struct Data {
+pub struct Data {
     seeds: Vec<RNG>,
+    params: Params,
 }
 
 impl Data {
-    fn from_str(input: &str) -> Self {
+    fn from_str(input: &str, params: Params) -> Result<Self, ParseError> {
         let seeds = input
             .lines()
-            .map(|line| line.parse().expect("Could not parse value"))
-            .collect();
+            .map(|line| {
+                let seed = parse_at(input, line)?;
+
+                // Anything else would not survive the first prune anyway (and might overflow before it)
+                if !(0..16777216).contains(&seed) {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "secret numbers must be between 0 and 16777215",
+                    ));
+                }
+
+                Ok(seed)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { seeds, params })
+    }
+}
 
-        Self { seeds }
+// How many new secret numbers every buyer generates in a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub steps: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { steps: 2000 }
     }
 }
 
-fn main() {
-    let mut input = String::new();
+pub struct Day;
 
-    File::open("./input.txt")
-        .expect("Could not open File")
-        .read_to_string(&mut input)
-        .expect("Could not read File");
+impl Solution for Day {
+    type Input = Data;
+    type Params = Params;
 
-    let data = Data::from_str(&input);
+    fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+        parse(input, params)
+    }
 
-    part_two(&data);
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str, params: Params) -> Result<Data, ParseError> {
+    Data::from_str(input, params)
 }
 
-fn part_one(data: &Data) {
+pub fn part_one(data: &Data) -> i64 {
     let mut sum = 0;
 
     for seed in &data.seeds {
         let mut seed = *seed;
-        for i in 0..2000 {
+        for _ in 0..data.params.steps {
             rng(&mut seed);
         }
 
         sum += seed;
     }
 
-    dbg!(sum);
+    sum
 }
 
-fn part_two(data: &Data) {
+pub fn part_two(data: &Data) -> Option<i64> {
     let mut sequences = vec![vec![]; data.seeds.len()];
 
     for (seed, sequence) in data.seeds.iter().zip(sequences.iter_mut()) {
         let mut seed = *seed;
         sequence.push(seed);
-        for i in 0..2000 {
+        for _ in 0..data.params.steps {
             rng(&mut seed);
             sequence.push(seed);
         }
@@ -71,7 +113,7 @@ fn part_two(data: &Data) {
         })
         .collect();
 
-    let max_val: Option<i64> = (-9..=9)
+    (-9..=9)
         .into_par_iter()
         .map(|a| {
             (-9..=9)
@@ -114,9 +156,7 @@ fn part_two(data: &Data) {
                 .max()
                 .unwrap_or(0)
         })
-        .max();
-
-    dbg!(max_val);
+        .max()
 }
 
 fn rng(seed: &mut RNG) {
@@ -146,3 +186,39 @@ fn mix(this: &mut RNG, other: RNG) {
 fn prune(this: &mut RNG) {
     *this %= 16777216;
 }
+
+#[cfg(test)]
+mod test {
+    use common::testing::{
+        check_generated_inputs, check_part_one, check_part_two, check_personal_part,
+    };
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), 37327623);
+    }
+
+    #[test]
+    fn test_example_part_two() {
+        check_part_two::<Day>(include_str!("../example_2.txt"), 23);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_part::<Day>(env!("CARGO_MANIFEST_DIR"), 1);
+    }
+
+    // Takes minutes
+    #[test]
+    #[ignore]
+    fn test_personal_part_two() {
+        check_personal_part::<Day>(env!("CARGO_MANIFEST_DIR"), 2);
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 20);
+    }
+}
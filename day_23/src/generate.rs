@@ -0,0 +1,52 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{
+    rngs::StdRng,
+    seq::{index, SliceRandom},
+    Rng, SeedableRng,
+};
+use std::collections::HashSet;
+
+// A network of `size` computers (at most 676, as names have two letters). Every computer
+// gets a few random connections, and a group of up to 13 computers is connected to each
+// other, which is the LAN party of part two.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut names: Vec<String> = ('a'..='z')
+        .flat_map(|a| ('a'..='z').map(move |b| format!("{a}{b}")))
+        .collect();
+    names.shuffle(&mut rng);
+    names.truncate(size);
+
+    let mut connections = HashSet::new();
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            connections.insert((a.min(b), a.max(b)));
+        }
+    };
+
+    for a in 0..names.len() {
+        for _ in 0..rng.gen_range(1..=6) {
+            connect(a, rng.gen_range(0..names.len()));
+        }
+    }
+
+    let party = index::sample(&mut rng, names.len(), names.len().min(13)).into_vec();
+    for (i, a) in party.iter().enumerate() {
+        for b in &party[i + 1..] {
+            connect(*a, *b);
+        }
+    }
+
+    let mut connections: Vec<(usize, usize)> = connections.into_iter().collect();
+    connections.sort();
+    connections.shuffle(&mut rng);
+
+    let mut input = String::new();
+    for (a, b) in connections {
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        input.push_str(&format!("{}-{}\n", names[a], names[b]));
+    }
+
+    input
+}
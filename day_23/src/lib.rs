@@ -0,0 +1,151 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use itertools::Itertools;
+use petgraph::{algo::maximal_cliques, prelude::GraphMap, Undirected};
+
+pub use generate::generate;
+
+pub struct Data {
+    network: GraphMap<Computer, (), Undirected>,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut network = GraphMap::new();
+
+        for line in input.lines() {
+            let (a, b) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, line, "expected a connection like kh-tc"))?;
+
+            network.add_edge(parse_computer(input, a)?, parse_computer(input, b)?, ());
+        }
+
+        Ok(Self { network })
+    }
+}
+
+// `name` is a slice of `input`, which is only needed to locate errors
+fn parse_computer(input: &str, name: &str) -> Result<Computer, ParseError> {
+    let mut chars = name.chars();
+
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(a), Some(b), None) if a.is_ascii_lowercase() && b.is_ascii_lowercase() => Ok((a, b)),
+        _ => Err(ParseError::at(
+            input,
+            name,
+            "expected a computer name of two lowercase letters",
+        )),
+    }
+}
+
+type Computer = (char, char);
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    let mut sets: Vec<Vec<Computer>> = data
+        .network
+        .nodes()
+        .flat_map(|node| traverse_recursive(data, node, 3, |end| end == node).into_iter())
+        .collect();
+
+    // Deduplicate
+    sets.retain(|set| set.is_sorted());
+
+    sets.iter()
+        .filter(|set| set.iter().any(|comp| comp.0 == 't'))
+        .count()
+}
+
+pub fn part_two(data: &Data) -> String {
+    let maximal = maximal_cliques(&data.network);
+
+    let mut participants: Vec<Computer> = maximal
+        .into_iter()
+        .max_by_key(|a| a.len())
+        .unwrap()
+        .into_iter()
+        .collect();
+
+    participants.sort();
+
+    participants
+        .iter()
+        .map(|(a, b)| format!("{}{}", a, b))
+        .join(",")
+}
+
+fn traverse_recursive(
+    data: &Data,
+    start_node: Computer,
+    max_depth: usize,
+    accept: impl Fn(Computer) -> bool + Clone,
+) -> Vec<Vec<Computer>> {
+    if max_depth == 0 {
+        if accept(start_node) {
+            return vec![vec![]];
+        } else {
+            return vec![];
+        }
+    }
+
+    let ret = data
+        .network
+        .neighbors(start_node)
+        .flat_map(|neighbor| {
+            traverse_recursive(data, neighbor, max_depth - 1, accept.clone())
+                .into_iter()
+                .map(|mut v| {
+                    v.push(start_node);
+                    v
+                })
+        })
+        .collect();
+
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 7, "co,de,ka,ta");
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 60);
+    }
+}
@@ -0,0 +1,144 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::HashSet;
+
+// A ripple carry adder for two `size` bit numbers with four pairs of swapped gate outputs,
+// like the real puzzle. See `generate_with_swaps`.
+pub fn generate(size: usize, seed: u64) -> String {
+    generate_with_swaps(size, 4, seed)
+}
+
+// A ripple carry adder for two `bits` bit numbers (at most 99, wire names have two digits),
+// with random values on the inputs. Then the outputs of `swaps` pairs of gates are swapped,
+// as long as that doesn't make the circuit loop.
+pub fn generate_with_swaps(bits: usize, swaps: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let bits = bits.clamp(1, 99);
+
+    let mut names = WireNames::default();
+    let mut gates = vec![];
+
+    let mut carry = names.next(&mut rng);
+    gates.push(["x00", "XOR", "y00", "z00"].map(String::from));
+    gates.push(["x00".to_string(), "AND".into(), "y00".into(), carry.clone()]);
+
+    for bit in 1..bits {
+        let (x, y, z) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("z{bit:02}"),
+        );
+        let half_sum = names.next(&mut rng);
+        let half_carry = names.next(&mut rng);
+        let carry_through = names.next(&mut rng);
+        let next_carry = if bit == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            names.next(&mut rng)
+        };
+
+        gates.push([x.clone(), "XOR".into(), y.clone(), half_sum.clone()]);
+        gates.push([x, "AND".into(), y, half_carry.clone()]);
+        gates.push([half_sum.clone(), "XOR".into(), carry.clone(), z]);
+        gates.push([half_sum, "AND".into(), carry, carry_through.clone()]);
+        gates.push([half_carry, "OR".into(), carry_through, next_carry.clone()]);
+
+        carry = next_carry;
+    }
+
+    if bits == 1 {
+        gates[1][3] = "z01".to_string();
+    }
+
+    let mut swapped = HashSet::new();
+    let mut attempts = 0;
+    while swapped.len() < swaps * 2 && attempts < 1000 {
+        attempts += 1;
+
+        let (a, b) = (rng.gen_range(0..gates.len()), rng.gen_range(0..gates.len()));
+        if a == b || swapped.contains(&a) || swapped.contains(&b) {
+            continue;
+        }
+
+        let (output_a, output_b) = (gates[a][3].clone(), gates[b][3].clone());
+        gates[a][3] = output_b.clone();
+        gates[b][3] = output_a.clone();
+
+        if has_loop(&gates, bits) {
+            gates[a][3] = output_a;
+            gates[b][3] = output_b;
+        } else {
+            swapped.extend([a, b]);
+        }
+    }
+
+    let mut input = String::new();
+    for wire in ['x', 'y'] {
+        for bit in 0..bits {
+            input.push_str(&format!("{wire}{bit:02}: {}\n", rng.gen_range(0..=1)));
+        }
+    }
+    input.push('\n');
+
+    gates.shuffle(&mut rng);
+    for [a, op, b, output] in gates {
+        let (a, b) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        input.push_str(&format!("{a} {op} {b} -> {output}\n"));
+    }
+
+    input
+}
+
+// Random three letter names for the wires in between, never starting with x, y or z
+#[derive(Default)]
+struct WireNames {
+    used: HashSet<String>,
+}
+
+impl WireNames {
+    fn next(&mut self, rng: &mut StdRng) -> String {
+        loop {
+            let name: String = [
+                rng.gen_range('a'..'x'),
+                rng.gen_range('a'..='z'),
+                rng.gen_range('a'..='z'),
+            ]
+            .iter()
+            .collect();
+
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+// Whether some gates can never get both of their inputs, because they (indirectly) need
+// their own output
+fn has_loop(gates: &[[String; 4]], bits: usize) -> bool {
+    let mut known: HashSet<&str> = HashSet::new();
+    let inputs: Vec<String> = (0..bits)
+        .flat_map(|bit| [format!("x{bit:02}"), format!("y{bit:02}")])
+        .collect();
+    known.extend(inputs.iter().map(String::as_str));
+
+    let mut remaining: Vec<&[String; 4]> = gates.iter().collect();
+    loop {
+        let before = remaining.len();
+        remaining.retain(|[a, _, b, output]| {
+            if known.contains(a.as_str()) && known.contains(b.as_str()) {
+                known.insert(output);
+                false
+            } else {
+                true
+            }
+        });
+
+        if remaining.is_empty() {
+            return false;
+        }
+        if remaining.len() == before {
+            return true;
+        }
+    }
+}
@@ -1,7 +1,9 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use log::{debug, trace};
 use std::{
     collections::{HashMap, HashSet},
-    fs::File,
-    io::Read,
     mem,
     ops::ControlFlow,
 };
@@ -12,60 +14,49 @@ use logicng::{
     operations::predicates::is_tautology,
 };
 
-struct Data {
+pub use generate::{generate, generate_with_swaps};
+
+pub struct Data {
     initial_wires: HashMap<Wire, bool>,
     gates: Vec<Gate>,
 }
 
 impl Data {
-    fn from_str(input: &str) -> Self {
-        let (wires, gates) = input.split_once("\n\n").expect("Could not find empty line");
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (wires, gates) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(input, "expected an empty line between wires and gates")
+        })?;
 
         let initial_wires = wires
             .lines()
-            .map(|line| line.split_once(' ').expect("Malformed wire input"))
-            .map(|(wire_name, wire_value)| {
-                (
-                    wire_from_str(wire_name.strip_suffix(':').unwrap()),
-                    wire_value.parse::<u8>().expect("Could not parse bool") > 0,
-                )
+            .map(|line| {
+                let (wire_name, wire_value) = line
+                    .split_once(": ")
+                    .ok_or_else(|| ParseError::at(input, line, "expected a wire like x00: 1"))?;
+
+                let value = match wire_value {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(ParseError::at(input, wire_value, "expected 0 or 1")),
+                };
+
+                Ok((wire_from_str(wire_name), value))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
         let gates = gates
             .lines()
-            .map(|line| line.split_ascii_whitespace())
-            .map(|mut words| {
-                let wire1 = wire_from_str(words.next().unwrap());
-                let ty = match words.next().unwrap() {
-                    "AND" => GateType::AND {
-                        inputs: [wire1, wire_from_str(words.next().unwrap())],
-                    },
-                    "OR" => GateType::OR {
-                        inputs: [wire1, wire_from_str(words.next().unwrap())],
-                    },
-                    "XOR" => GateType::XOR {
-                        inputs: [wire1, wire_from_str(words.next().unwrap())],
-                    },
-                    _ => unreachable!(),
-                };
+            .map(|line| Gate::from_str(input, line))
+            .collect::<Result<_, _>>()?;
 
-                assert_eq!(words.next(), Some("->"));
-
-                Gate {
-                    ty,
-                    output: wire_from_str(words.next().unwrap()),
-                }
-            })
-            .collect();
-
-        Self {
+        Ok(Self {
             initial_wires,
             gates,
-        }
+        })
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, PartialEq)]
 enum GateType {
     AND { inputs: [Wire; 2] },
@@ -79,26 +70,66 @@ struct Gate {
     output: Wire,
 }
 
+impl Gate {
+    // `line` is a slice of `input`, which is only needed to locate errors
+    fn from_str(input: &str, line: &str) -> Result<Self, ParseError> {
+        let malformed = || ParseError::at(input, line, "expected a gate like x00 AND y00 -> z00");
+
+        let [wire1, ty, wire2, arrow, output] = line
+            .split_ascii_whitespace()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| malformed())?;
+
+        if arrow != "->" {
+            return Err(malformed());
+        }
+
+        let inputs = [wire_from_str(wire1), wire_from_str(wire2)];
+        let ty = match ty {
+            "AND" => GateType::AND { inputs },
+            "OR" => GateType::OR { inputs },
+            "XOR" => GateType::XOR { inputs },
+            _ => return Err(ParseError::at(input, ty, "expected AND, OR or XOR")),
+        };
+
+        Ok(Gate {
+            ty,
+            output: wire_from_str(output),
+        })
+    }
+}
+
 type Wire = String;
 
 fn wire_from_str(input: &str) -> Wire {
     input.to_string()
 }
 
-fn main() {
-    let mut input = String::new();
+pub struct Day;
 
-    File::open("./input.txt")
-        .expect("Could not open File")
-        .read_to_string(&mut input)
-        .expect("Could not read File");
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
 
-    let data = Data::from_str(&input);
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
 
-    part_two(&data);
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
 }
 
-fn part_one(data: &Data) {
+pub fn part_one(data: &Data) -> u64 {
     let mut values = data.initial_wires.clone();
 
     partial_evaluate(&data.gates, &mut values);
@@ -106,18 +137,20 @@ fn part_one(data: &Data) {
     let mut place = 0;
     let mut final_value = 0;
 
-    dbg!(&values);
+    trace!("wire values {values:?}");
 
     while let Some(val) = values.get(&format!("z{place:02}")) {
         final_value |= u64::from(*val) << place;
         place += 1;
     }
 
-    dbg!(final_value);
+    final_value
 }
 
-fn part_two(data: &Data) {
-    let ret = solve(data);
+// None if some bit of the adder can't be fixed with at most two swaps among the gates it
+// depends on
+pub fn part_two(data: &Data) -> Option<String> {
+    let ret = solve(data)?;
 
     let mut res = vec![];
     for (before, after) in data.gates.iter().zip(ret) {
@@ -128,7 +161,7 @@ fn part_two(data: &Data) {
 
     res.sort();
 
-    println!("{}", res.iter().join(","))
+    Some(res.iter().join(","))
 }
 
 #[derive(Debug)]
@@ -144,7 +177,7 @@ fn check_is_adder(gates: &[Gate], num_input_digits: usize) -> Result<(), CheckFa
     for place in 0..num_input_digits {
         let s = format!("z{place:02}");
 
-        let Ok(formula) = formula_for_wire(gates, &s, &fac, &Box::new(&|_| false)) else {
+        let Ok(formula) = formula_for_wire(gates, &s, &fac, &|_| false) else {
             return Err(CheckFailureType::Loop);
         };
 
@@ -168,7 +201,7 @@ fn formula_for_wire(
     gates: &[Gate],
     wire: &Wire,
     factory: &FormulaFactory,
-    abort: &Box<&dyn Fn(&Wire) -> bool>,
+    abort: &dyn Fn(&Wire) -> bool,
 ) -> Result<EncodedFormula, ()> {
     if abort(wire) {
         return Err(());
@@ -177,47 +210,29 @@ fn formula_for_wire(
     Ok(match gates.iter().find(|g| g.output == *wire) {
         Some(gate) => match &gate.ty {
             GateType::AND { inputs } => factory.and(&[
-                formula_for_wire(
-                    gates,
-                    &inputs[0],
-                    factory,
-                    &Box::new(&move |lower_wire: &String| lower_wire == wire || abort(lower_wire)),
-                )?,
-                formula_for_wire(
-                    gates,
-                    &inputs[1],
-                    factory,
-                    &Box::new(&move |lower_wire: &String| lower_wire == wire || abort(lower_wire)),
-                )?,
+                formula_for_wire(gates, &inputs[0], factory, &move |lower_wire: &String| {
+                    lower_wire == wire || abort(lower_wire)
+                })?,
+                formula_for_wire(gates, &inputs[1], factory, &move |lower_wire: &String| {
+                    lower_wire == wire || abort(lower_wire)
+                })?,
             ]),
             GateType::OR { inputs } => factory.or(&[
-                formula_for_wire(
-                    gates,
-                    &inputs[0],
-                    factory,
-                    &Box::new(&move |lower_wire: &String| lower_wire == wire || abort(lower_wire)),
-                )?,
-                formula_for_wire(
-                    gates,
-                    &inputs[1],
-                    factory,
-                    &Box::new(&move |lower_wire: &String| lower_wire == wire || abort(lower_wire)),
-                )?,
+                formula_for_wire(gates, &inputs[0], factory, &move |lower_wire: &String| {
+                    lower_wire == wire || abort(lower_wire)
+                })?,
+                formula_for_wire(gates, &inputs[1], factory, &move |lower_wire: &String| {
+                    lower_wire == wire || abort(lower_wire)
+                })?,
             ]),
             GateType::XOR { inputs } => xor(
                 factory,
-                formula_for_wire(
-                    gates,
-                    &inputs[0],
-                    factory,
-                    &Box::new(&move |lower_wire: &String| lower_wire == wire || abort(lower_wire)),
-                )?,
-                formula_for_wire(
-                    gates,
-                    &inputs[1],
-                    factory,
-                    &Box::new(&move |lower_wire: &String| lower_wire == wire || abort(lower_wire)),
-                )?,
+                formula_for_wire(gates, &inputs[0], factory, &move |lower_wire: &String| {
+                    lower_wire == wire || abort(lower_wire)
+                })?,
+                formula_for_wire(gates, &inputs[1], factory, &move |lower_wire: &String| {
+                    lower_wire == wire || abort(lower_wire)
+                })?,
             ),
         },
         None => factory.variable(wire),
@@ -326,7 +341,7 @@ fn get_influencers(gates: &[Gate], wire: &Wire, include_finals: bool) -> HashSet
     }
 }
 
-fn solve(data: &Data) -> Vec<Gate> {
+fn solve(data: &Data) -> Option<Vec<Gate>> {
     let mut num_digits_input = 0;
 
     while data
@@ -354,7 +369,7 @@ fn solve(data: &Data) -> Vec<Gate> {
                 CheckFailureType::Loop => unreachable!(),
             };
 
-            println!("{problems_left:b}");
+            debug!("bits still wrong: {problems_left:b}");
 
             assert!(problems_left.count_ones() <= problems.count_ones());
 
@@ -367,7 +382,7 @@ fn solve(data: &Data) -> Vec<Gate> {
                     continue;
                 }
 
-                println!("Locking {old_prob}");
+                debug!("locking the gates of bit {old_prob}");
                 let output = format!("z{old_prob:02}");
                 // these are newly okay
                 let influencers = get_influencers(&gates, &output, false);
@@ -394,11 +409,11 @@ fn solve(data: &Data) -> Vec<Gate> {
         let output = format!("z{:02}", problems.trailing_zeros());
         let current_influencers = get_influencers(&gates, &output, false);
 
-        dbg!(&current_influencers);
-        dbg!(&locked_gates);
+        trace!("influencers {current_influencers:?}");
+        trace!("locked gates {locked_gates:?}");
 
         for num_swaps in 1..=2 {
-            println!("Trying {num_swaps} swaps");
+            debug!("trying {num_swaps} swaps");
             let ret = try_out_swaps(
                 &mut gates,
                 &current_influencers,
@@ -426,7 +441,7 @@ fn solve(data: &Data) -> Vec<Gate> {
                 },
             );
 
-            dbg!(ret);
+            trace!("{ret:?}");
 
             match ret {
                 ControlFlow::Continue(_) => continue,
@@ -434,12 +449,13 @@ fn solve(data: &Data) -> Vec<Gate> {
             }
         }
 
-        todo!("No solution with x swaps");
+        debug!("no swaps fix bit {}", problems.trailing_zeros());
+        return None;
     }
 
     assert!(check_is_adder(&gates, num_digits_input).is_ok());
 
-    gates
+    Some(gates)
 }
 
 fn try_out_swaps(
@@ -469,7 +485,7 @@ fn try_out_swaps(
             continue;
         }
 
-        dbg!(influencer);
+        trace!("swapping {influencer}");
         for j in 0..num_gates {
             if locked_gates[j] {
                 continue;
@@ -479,7 +495,7 @@ fn try_out_swaps(
                 continue;
             }
 
-            dbg!(j);
+            trace!("with gate {j}");
 
             // Swap them
 
@@ -518,3 +534,38 @@ fn try_out_swaps(
 
     ControlFlow::Continue(())
 }
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_generated_inputs, check_part_one, check_personal_answers};
+
+    use crate::{generate, generate_with_swaps, parse, part_two, Day};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), 4);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 16);
+    }
+
+    #[test]
+    fn test_generated_swaps() {
+        // Some of these swap gates that part two can't fix, then it gives up instead of
+        // panicking
+        for seed in 0..4 {
+            let data = parse(&generate_with_swaps(8, 4, seed)).unwrap();
+
+            if let Some(wires) = part_two(&data) {
+                assert!(wires.split(',').count() <= 8, "seed {seed}: {wires}");
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// `size` schematics, about half of them locks and the other half keys
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut schematics = vec![];
+    for _ in 0..size {
+        let is_lock = rng.gen_bool(0.5);
+        let heights: Vec<usize> = (0..5).map(|_| rng.gen_range(0..=5)).collect();
+
+        let mut schematic = String::new();
+        for row in 0..7 {
+            for height in &heights {
+                // Locks fill up from the top, keys from the bottom
+                let filled = if is_lock {
+                    row <= *height
+                } else {
+                    6 - row <= *height
+                };
+                schematic.push(if filled { '#' } else { '.' });
+            }
+            schematic.push('\n');
+        }
+
+        schematics.push(schematic);
+    }
+
+    schematics.join("\n")
+}
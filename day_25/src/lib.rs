@@ -0,0 +1,156 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+
+pub use generate::generate;
+
+struct Data {
+    keys: Vec<[[bool; 5]; 5]>,
+    locks: Vec<[[bool; 5]; 5]>,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut keys = vec![];
+        let mut locks = vec![];
+
+        for thing in input.split("\n\n") {
+            let mut lines = thing.lines();
+
+            let first_line = lines.next();
+
+            let content: Vec<[bool; 5]> = lines
+                .take(5)
+                .map(|line| {
+                    line.char_indices()
+                        .map(|(i, c)| match c {
+                            '#' => Ok(true),
+                            '.' => Ok(false),
+                            _ => Err(ParseError::at(
+                                input,
+                                &line[i..i + c.len_utf8()],
+                                "expected '#' or '.'",
+                            )),
+                        })
+                        .collect::<Result<Vec<bool>, _>>()?
+                        .try_into()
+                        .map_err(|_| ParseError::at(input, line, "expected a row of 5 pins"))
+                })
+                .collect::<Result<_, _>>()?;
+
+            let content: [[bool; 5]; 5] = content
+                .try_into()
+                .map_err(|_| ParseError::at(input, thing, "expected 5 rows of pins"))?;
+
+            if first_line == Some("#####") {
+                // This is a lock
+                locks.push(content);
+            } else if first_line == Some(".....") {
+                // This is a key
+                keys.push(content);
+            } else {
+                return Err(ParseError::at(
+                    input,
+                    thing,
+                    "expected a lock (#####) or a key (.....)",
+                ));
+            }
+        }
+
+        Ok(Self { keys, locks })
+    }
+}
+
+pub struct BitMapData {
+    keys: Vec<u32>,
+    locks: Vec<u32>,
+}
+
+impl From<Data> for BitMapData {
+    fn from(value: Data) -> Self {
+        let keys = value
+            .keys
+            .iter()
+            .map(|key| {
+                key.iter().fold(0, |acc, line| {
+                    line.iter()
+                        .fold(acc, |acc, bit| (acc << 1) | u32::from(*bit))
+                })
+            })
+            .collect();
+
+        let locks = value
+            .locks
+            .iter()
+            .map(|key| {
+                key.iter().fold(0, |acc, line| {
+                    line.iter()
+                        .fold(acc, |acc, bit| (acc << 1) | u32::from(*bit))
+                })
+            })
+            .collect();
+
+        Self { keys, locks }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BitMapData;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<BitMapData, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &BitMapData) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(_data: &BitMapData) -> Answer {
+        // Day 25 only has one part
+        Answer::None
+    }
+}
+
+pub fn parse(input: &str) -> Result<BitMapData, ParseError> {
+    Ok(Data::from_str(input)?.into())
+}
+
+pub fn part_one(data: &BitMapData) -> usize {
+    let mut count = 0;
+
+    for key in &data.keys {
+        for lock in &data.locks {
+            if key & lock == 0 {
+                // They do not intersect
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_generated_inputs, check_part_one, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), 3);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 50);
+    }
+}
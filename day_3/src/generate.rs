@@ -0,0 +1,40 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// Roughly `size` characters of corrupted memory. Valid instructions are mixed with
+// almost-valid ones and random junk.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    const JUNK: &[u8] = b"!@#$%^&*()[]{}<>,;:'?+-_/ whatfromselectwhyhow0123456789";
+
+    let mut input = String::new();
+    while input.len() < size {
+        let piece = match rng.gen_range(0..10) {
+            0..=2 => format!("mul({},{})", rng.gen_range(0..1000), rng.gen_range(0..1000)),
+            3 => "do()".to_string(),
+            4 => "don't()".to_string(),
+            5 => [
+                "mul(4*",
+                "mul(6,9!",
+                "?(12,34)",
+                "mul ( 2 , 4 )",
+                "mul(32,64]",
+                "don't",
+                "do(",
+            ]
+            .choose(&mut rng)
+            .unwrap()
+            .to_string(),
+            _ => (0..rng.gen_range(1..8))
+                .map(|_| *JUNK.choose(&mut rng).unwrap() as char)
+                .collect(),
+        };
+
+        input.push_str(&piece);
+    }
+
+    input.push('\n');
+
+    input
+}
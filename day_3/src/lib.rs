@@ -0,0 +1,176 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use log::trace;
+
+pub use generate::generate;
+
+#[derive(Debug)]
+pub struct Data {
+    instructions: Vec<Instruction>,
+}
+
+// The instructions that survived the corruption, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+// Anything goes, the corrupted memory is filtered here. It is scanned byte by byte, all
+// instructions are ASCII, so anything else never matches and is skipped.
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    let memory = input.as_bytes();
+
+    let mut instructions = Vec::new();
+    let mut pos = 0;
+    while pos < memory.len() {
+        let rest = &memory[pos..];
+
+        if let Some((instruction, len)) = try_eat_instruction(rest) {
+            instructions.push(instruction);
+            pos += len;
+        } else {
+            pos += 1;
+        }
+    }
+
+    Ok(Data { instructions })
+}
+
+// None if the sum doesn't fit into a u64
+pub fn part_one(data: &Data) -> Option<u64> {
+    let mut sum: u64 = 0;
+
+    for instruction in &data.instructions {
+        if let Instruction::Mul(a, b) = instruction {
+            sum = sum.checked_add(a.checked_mul(*b)?)?;
+
+            trace!("mul({a},{b})");
+        }
+    }
+
+    Some(sum)
+}
+
+pub fn part_two(data: &Data) -> Option<u64> {
+    let mut sum: u64 = 0;
+    let mut enabled = true;
+
+    for instruction in &data.instructions {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Mul(a, b) => {
+                if enabled {
+                    sum = sum.checked_add(a.checked_mul(*b)?)?;
+                }
+
+                trace!("mul({a},{b}), enabled: {enabled}");
+            }
+        }
+    }
+
+    Some(sum)
+}
+
+// The instruction at the start of `s` and how many bytes it takes
+fn try_eat_instruction(s: &[u8]) -> Option<(Instruction, usize)> {
+    if s.starts_with(b"do()") {
+        Some((Instruction::Do, 4))
+    } else if s.starts_with(b"don't()") {
+        Some((Instruction::Dont, 7))
+    } else {
+        try_eat_mul(s)
+    }
+}
+
+fn try_eat_mul(s: &[u8]) -> Option<(Instruction, usize)> {
+    let args = s.strip_prefix(b"mul(")?;
+
+    let (a, a_len) = try_eat_number(args)?;
+    let rest = args[a_len..].strip_prefix(b",")?;
+
+    let (b, b_len) = try_eat_number(rest)?;
+    rest[b_len..].strip_prefix(b")")?;
+
+    Some((Instruction::Mul(a, b), 4 + a_len + 1 + b_len + 1))
+}
+
+// 1 to 3 digits, like the puzzle says
+fn try_eat_number(s: &[u8]) -> Option<(u64, usize)> {
+    let len = s.iter().take_while(|c| c.is_ascii_digit()).count();
+
+    if !(1..=3).contains(&len) {
+        return None;
+    }
+
+    let value = s[..len]
+        .iter()
+        .fold(0, |value, digit| value * 10 + u64::from(digit - b'0'));
+
+    Some((value, len))
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{
+        check_generated_inputs, check_part_one, check_part_two, check_personal_answers,
+    };
+
+    use crate::{generate, parse, part_one, part_two, Day};
+
+    #[test]
+    fn test_example_part_one() {
+        check_part_one::<Day>(include_str!("../example.txt"), 161);
+    }
+
+    #[test]
+    fn test_example_part_two() {
+        check_part_two::<Day>(include_str!("../example_2.txt"), 48);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 2000);
+    }
+
+    #[test]
+    fn test_corrupted_memory() {
+        for input in ["é", "éé", "mul(é", "mul(1é,2)", "mul(2,é)", "mul(2,3é"] {
+            assert_eq!(part_one(&parse(input).unwrap()), Some(0), "{input:?}");
+        }
+
+        let data = parse("émul(2,3)ödon't()mul(1234,5)mul(4,5)do()mul(999,999)").unwrap();
+        assert_eq!(part_one(&data), Some(6 + 20 + 998001));
+        assert_eq!(part_two(&data), Some(6 + 998001));
+
+        let data = parse("mul(99999999999,99999999999)").unwrap();
+        assert_eq!(part_one(&data), Some(0));
+    }
+}
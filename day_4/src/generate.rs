@@ -0,0 +1,15 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+// A `size` x `size` word search made of the letters X, M, A and S
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    for _ in 0..size {
+        input.extend((0..size).map(|_| *['X', 'M', 'A', 'S'].choose(&mut rng).unwrap()));
+        input.push('\n');
+    }
+
+    input
+}
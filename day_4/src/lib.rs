@@ -0,0 +1,118 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use grid::{Dir, Grid, Pos, Vec2};
+
+pub use generate::generate;
+
+pub struct WordSearch {
+    letters: Grid<char>,
+}
+
+impl WordSearch {
+    fn get_offset(&self, pos: Pos, offset: Vec2) -> Option<&char> {
+        self.letters.get_signed(Vec2::from(pos) + offset)
+    }
+
+    fn num_xmas_at_pos(&self, pos: Pos) -> usize {
+        if self.letters[pos] != 'X' {
+            return 0;
+        }
+
+        let mut count = 0;
+        for dir in Dir::all() {
+            let offs = dir.offset();
+
+            if self.get_offset(pos, offs) == Some(&'M')
+                && self.get_offset(pos, offs * 2) == Some(&'A')
+                && self.get_offset(pos, offs * 3) == Some(&'S')
+            {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    fn has_mas_cross_at_pos(&self, pos: Pos) -> bool {
+        if self.letters[pos] != 'A' {
+            return false;
+        }
+
+        let mut m_dir = vec![];
+        let mut s_count = 0;
+        for diag in Dir::diagonals() {
+            if self.get_offset(pos, diag.offset()) == Some(&'M') {
+                m_dir.push(diag);
+            } else if self.get_offset(pos, diag.offset()) == Some(&'S') {
+                s_count += 1;
+            }
+        }
+
+        m_dir.len() == 2 && s_count == 2 && m_dir[0].opposite() != m_dir[1]
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = WordSearch;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<WordSearch, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &WordSearch) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &WordSearch) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<WordSearch, ParseError> {
+    // Any letter is fine, the parts only look for XMAS
+    Ok(WordSearch {
+        letters: Grid::parse(input, |_, c| Ok(c))?,
+    })
+}
+
+pub fn part_one(search: &WordSearch) -> usize {
+    search
+        .letters
+        .positions()
+        .map(|pos| search.num_xmas_at_pos(pos))
+        .sum()
+}
+
+pub fn part_two(search: &WordSearch) -> usize {
+    search
+        .letters
+        .positions()
+        .filter(|pos| search.has_mas_cross_at_pos(*pos))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 18, 9);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 30);
+    }
+}
@@ -0,0 +1,46 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// `size` updates over (at most) 89 two-digit pages. Like in the real puzzle there is a rule
+// for every pair of pages, all consistent with one hidden order, and about half of the
+// updates are already in the right order.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut order: Vec<usize> = (10..100).collect();
+    order.shuffle(&mut rng);
+    order.truncate(rng.gen_range(23..=order.len()));
+
+    let mut rules = vec![];
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push((*before, *after));
+        }
+    }
+    rules.shuffle(&mut rng);
+
+    let mut input = String::new();
+    for (before, after) in rules {
+        input.push_str(&format!("{before}|{after}\n"));
+    }
+    input.push('\n');
+
+    for _ in 0..size {
+        // Updates always have a middle page
+        let len = rng.gen_range(2..=11) * 2 + 1;
+
+        let mut indices: Vec<usize> = (0..order.len()).collect();
+        indices.shuffle(&mut rng);
+        indices.truncate(len);
+
+        if rng.gen_bool(0.5) {
+            indices.sort();
+        }
+
+        let update: Vec<String> = indices.iter().map(|idx| order[*idx].to_string()).collect();
+        input.push_str(&update.join(","));
+        input.push('\n');
+    }
+
+    input
+}
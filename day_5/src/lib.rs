@@ -1,14 +1,20 @@
-use std::{cmp, fs::File, io::Read};
+mod generate;
+
+use common::{parse_at, Answer, NoParams, ParseError, Solution};
+use log::trace;
+use std::cmp;
+
+pub use generate::generate;
 
 #[derive(Debug, Clone)]
-struct Data {
+pub struct Data {
     orderings: Vec<Ordering>,
 
     pages: Vec<Vec<Page>>,
 }
 
 impl Data {
-    fn from_str(input: &str) -> Self {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
         let mut orderings = vec![];
         let mut pages = vec![];
 
@@ -20,13 +26,17 @@ impl Data {
             }
 
             if !orderings_done {
-                orderings.push(Ordering::from_str(line));
+                orderings.push(Ordering::from_str(input, line)?);
             } else {
-                pages.push(line.split(',').flat_map(str::parse).map(Page).collect())
+                pages.push(
+                    line.split(',')
+                        .map(|page| parse_at(input, page).map(Page))
+                        .collect::<Result<_, _>>()?,
+                )
             }
         }
 
-        Self { orderings, pages }
+        Ok(Self { orderings, pages })
     }
 }
 
@@ -61,13 +71,13 @@ impl Page {
 struct Ordering(usize, usize);
 
 impl Ordering {
-    fn from_str(input: &str) -> Self {
-        let (a, b) = input.split_once('|').expect("Wrong pattern for Ordering");
+    // `line` is a slice of `input`, which is only needed to locate errors
+    fn from_str(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (a, b) = line
+            .split_once('|')
+            .ok_or_else(|| ParseError::at(input, line, "expected an ordering like 47|53"))?;
 
-        Self(
-            str::parse(a).expect("ordering not number"),
-            str::parse(b).expect("ordering not number"),
-        )
+        Ok(Self(parse_at(input, a)?, parse_at(input, b)?))
     }
 
     fn satisfies(&self, data: &[Page]) -> bool {
@@ -79,34 +89,44 @@ impl Ordering {
     }
 }
 
-fn main() {
-    let mut input = String::new();
+pub struct Day;
 
-    File::open("./input.txt")
-        .expect("Could not open File")
-        .read_to_string(&mut input)
-        .expect("Could not read File");
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
 
-    let data = Data::from_str(&input);
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
 
-    part_two_graph(&data);
+    fn part_two(data: &Data) -> Answer {
+        part_two_graph(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
 }
 
-fn part_one(data: &Data) {
+pub fn part_one(data: &Data) -> usize {
     let mut sum = 0;
 
     for page_list in &data.pages {
         if data.orderings.iter().all(|ord| ord.satisfies(page_list)) {
-            dbg!(page_list);
+            trace!("correctly ordered: {page_list:?}");
 
             sum += page_list[page_list.len() / 2].0;
         }
     }
 
-    dbg!(sum);
+    sum
 }
 
-fn part_two(data: &Data) {
+pub fn part_two(data: &Data) -> usize {
     let mut data = data.clone();
 
     let mut sum = 0;
@@ -125,10 +145,10 @@ fn part_two(data: &Data) {
         }
     }
 
-    dbg!(sum);
+    sum
 }
 
-fn part_two_graph(data: &Data) {
+pub fn part_two_graph(data: &Data) -> usize {
     let mut graph: petgraph::graphmap::GraphMap<usize, (), petgraph::Directed> =
         petgraph::graphmap::GraphMap::new();
 
@@ -174,5 +194,27 @@ fn part_two_graph(data: &Data) {
         }
     }
 
-    dbg!(sum);
+    sum
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 143, 123);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 20);
+    }
 }
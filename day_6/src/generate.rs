@@ -0,0 +1,44 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use grid::{Dir, Grid, Pos};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+
+// A `size` x `size` lab with obstructions scattered over it, and a guard facing up who
+// leaves the lab eventually. Maps where the guard would walk in circles, or leaves before
+// visiting at least `size` tiles, are thrown away.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    loop {
+        let mut map = Grid::from_fn(size, size, |_| if rng.gen_bool(0.05) { '#' } else { '.' });
+
+        let guard = Pos::new(rng.gen_range(0..size), rng.gen_range(0..size));
+        map[guard] = '^';
+
+        if guard_patrol(&map, guard).is_some_and(|visited| visited >= size) {
+            return map.to_string();
+        }
+    }
+}
+
+// How many tiles the guard visits before leaving the lab, None if they never leave
+fn guard_patrol(map: &Grid<char>, mut pos: Pos) -> Option<usize> {
+    let mut dir = Dir::U;
+    let mut seen = HashSet::new();
+
+    while seen.insert((pos, dir)) {
+        let Some(next) = map.step(pos, dir.offset()) else {
+            let visited: HashSet<Pos> = seen.iter().map(|(pos, _)| *pos).collect();
+            return Some(visited.len());
+        };
+
+        if map[next] == '#' {
+            dir = dir.turn_right();
+        } else {
+            pos = next;
+        }
+    }
+
+    None
+}
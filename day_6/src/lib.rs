@@ -0,0 +1,582 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use grid::{Dir, Grid, Vec2};
+use render::{Animate, Animation, Cell, Color, Exploration, Explore, Frame, Recorder};
+use std::collections::{HashMap, HashSet};
+
+pub use generate::generate;
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<MapPos>,
+    player_pos: Vec2,
+    player_dir: Dir,
+}
+
+impl Map {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut player = None;
+
+        let tiles = Grid::parse(input, |pos, char| {
+            let dir = match char {
+                '.' => return Ok(MapPos::Empty),
+                '#' => return Ok(MapPos::Blocked),
+                _ => Dir::from_arrow(char).ok_or_else(|| {
+                    ParseError::new(pos.y + 1, pos.x + 1, char, "expected one of . # ^ > v <")
+                })?,
+            };
+
+            if player.is_some() {
+                return Err(ParseError::new(
+                    pos.y + 1,
+                    pos.x + 1,
+                    char,
+                    "found a second guard",
+                ));
+            }
+
+            player = Some((Vec2::from(pos), dir));
+
+            Ok(MapPos::Empty)
+        })?;
+
+        let (player_pos, player_dir) =
+            player.ok_or_else(|| ParseError::at_end(input, "no guard found"))?;
+
+        Ok(Self {
+            tiles,
+            player_pos,
+            player_dir,
+        })
+    }
+
+    fn get(&self, pos: Vec2) -> Option<&MapPos> {
+        self.tiles.get_signed(pos)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum MapPos {
+    Empty,
+    Blocked,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Map, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Map) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Map) -> Answer {
+        part_two_smart_bruteforce(data).into()
+    }
+}
+
+// Part one follows the guard, part two shows the obstacles that make it walk in a loop
+impl Animate for Day {
+    fn animate(map: &Map, part: u8, animation: &mut Animation) {
+        match part {
+            1 => {
+                patrol(map, animation);
+            }
+            _ => {
+                find_loop_obstacles(map, animation);
+            }
+        }
+    }
+}
+
+// Steps through the patrol, with the tiles the guard visits and the obstacles of part two
+impl Explore for Day {
+    fn explore(map: &Map) -> Exploration<'_> {
+        let route = route(map);
+        let visited: HashSet<Vec2> = route.iter().map(|(pos, _)| *pos).collect();
+        let loop_obstacles: HashSet<Vec2> = find_loop_obstacles(map, &mut ()).into_iter().collect();
+
+        let positions = |positions: &HashSet<Vec2>| {
+            positions
+                .iter()
+                .filter_map(|pos| pos.to_pos())
+                .collect::<Vec<_>>()
+        };
+
+        Exploration::with_steps(route.len(), move |step| {
+            draw_map(
+                map,
+                route[..=step].iter().map(|(pos, _)| *pos),
+                Some(route[step]),
+            )
+        })
+        .with_overlay("visited", Color::Blue, positions(&visited))
+        .with_overlay("loop obstacles", Color::Red, positions(&loop_obstacles))
+        .with_inspector(move |pos| {
+            let pos = Vec2::from(pos);
+            let mut text = match map.get(pos) {
+                Some(MapPos::Blocked) => "obstacle".to_string(),
+                _ => "floor".to_string(),
+            };
+
+            if pos == map.player_pos {
+                text += ", where the guard starts";
+            }
+            if visited.contains(&pos) {
+                text += ", visited";
+            }
+            if loop_obstacles.contains(&pos) {
+                text += ", an obstacle here makes the guard walk in a loop";
+            }
+
+            text
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    Map::from_str(input)
+}
+
+pub fn part_one(map: &Map) -> usize {
+    patrol(map, &mut ()).len()
+}
+
+// The tiles the guard walks over before leaving the lab
+fn patrol(map: &Map, recorder: &mut impl Recorder) -> HashSet<Vec2> {
+    let mut visited_positions = HashSet::new();
+
+    for (player_pos, player_dir) in route(map) {
+        visited_positions.insert(player_pos);
+
+        recorder.record(|| {
+            draw_map(
+                map,
+                visited_positions.iter().copied(),
+                Some((player_pos, player_dir)),
+            )
+            .with_caption(format!("{} tiles visited", visited_positions.len()))
+        });
+    }
+
+    visited_positions
+}
+
+// Where the guard is and where it looks, from the start until it leaves the lab
+fn route(map: &Map) -> Vec<(Vec2, Dir)> {
+    let mut player_pos = map.player_pos;
+    let mut player_dir = map.player_dir;
+
+    let mut route = vec![(player_pos, player_dir)];
+
+    while let Some(pos) = { map.get(player_pos + player_dir.offset()) } {
+        match pos {
+            MapPos::Empty => player_pos += player_dir.offset(),
+            MapPos::Blocked => player_dir = player_dir.turn_right(),
+        }
+
+        route.push((player_pos, player_dir));
+    }
+
+    route
+}
+
+// The lab with the tiles the guard walked over, and the guard itself
+fn draw_map(map: &Map, visited: impl Iterator<Item = Vec2>, guard: Option<(Vec2, Dir)>) -> Frame {
+    let frame = Frame::new(&map.tiles, |_, tile| match tile {
+        MapPos::Empty => Cell::new('.').fg(Color::BrightBlack),
+        MapPos::Blocked => Cell::new('#'),
+    })
+    .overlay(visited.filter_map(Vec2::to_pos), |_| {
+        Cell::new('X').fg(Color::Yellow)
+    });
+
+    match guard {
+        Some((pos, dir)) => frame.overlay(pos.to_pos(), |_| {
+            Cell::new(dir.arrow().unwrap_or('@')).fg(Color::Red)
+        }),
+        None => frame,
+    }
+}
+
+pub fn part_two_simple_bruteforce(map: &Map) -> usize {
+    // Do a normal run first
+    let mut visited_positions = HashSet::new();
+
+    visited_positions.insert(map.player_pos);
+
+    let mut player_pos = map.player_pos;
+    let mut player_dir = map.player_dir;
+
+    while let Some(pos) = { map.get(player_pos + player_dir.offset()) } {
+        let go_offset = player_dir.offset();
+        match pos {
+            MapPos::Empty => {
+                player_pos += go_offset;
+                visited_positions.insert(player_pos);
+            }
+            MapPos::Blocked => {
+                player_dir = player_dir.turn_right();
+                visited_positions.insert(player_pos);
+            }
+        }
+    }
+
+    let mut num_loops = 0;
+    for extra_obstacle_pos in map.tiles.positions().map(Vec2::from) {
+        if extra_obstacle_pos == map.player_pos {
+            // The guard is there right now and would notice
+            continue;
+        }
+
+        if !visited_positions.contains(&extra_obstacle_pos) {
+            // We don't hit the obstacle, no need to run
+            continue;
+        }
+
+        if contains_loop(map, extra_obstacle_pos, None) {
+            num_loops += 1;
+        }
+    }
+
+    num_loops
+}
+
+pub fn part_two_smart_bruteforce(map: &Map) -> usize {
+    find_loop_obstacles(map, &mut ()).len()
+}
+
+// Where a new obstacle would make the guard walk in a loop
+fn find_loop_obstacles(map: &Map, recorder: &mut impl Recorder) -> Vec<Vec2> {
+    let mut visited_positions = HashMap::new();
+
+    visited_positions.insert((map.player_pos, map.player_dir), 0);
+
+    let mut player_pos = map.player_pos;
+    let mut player_dir = map.player_dir;
+    let mut step = 0;
+
+    while let Some(pos) = {
+        let go_offset = player_dir.offset();
+        let go_to_pos = player_pos + go_offset;
+
+        map.get(go_to_pos)
+    } {
+        let go_offset = player_dir.offset();
+        match pos {
+            MapPos::Empty => {
+                player_pos += go_offset;
+                // We moved, check if we reached a pos we already had!
+                if visited_positions
+                    .insert((player_pos, player_dir), step)
+                    .is_some()
+                {
+                    // We were here once and looked in the same direction.
+                    // This means this is a loop!
+                    unreachable!("Reaching this code means the starting input contains a loop!");
+                }
+            }
+            MapPos::Blocked => {
+                player_dir = player_dir.turn_right();
+            }
+        }
+
+        step += 1;
+    }
+
+    let mut loop_obstacles = vec![];
+    for extra_obstacle_pos in map.tiles.positions().map(Vec2::from) {
+        if extra_obstacle_pos == map.player_pos {
+            // The guard is there right now and would notice
+            continue;
+        }
+
+        let mut earliest_step_hit = None;
+        for (step, dir) in Dir::cardinals().filter_map(|dir| {
+            visited_positions
+                .get(&(extra_obstacle_pos, dir))
+                .map(|step| (step, dir))
+        }) {
+            if let Some((earliest_step, _)) = earliest_step_hit {
+                if step < earliest_step {
+                    earliest_step_hit = Some((step, dir))
+                }
+            } else {
+                earliest_step_hit = Some((step, dir))
+            }
+        }
+
+        if let Some((_, dir)) = earliest_step_hit {
+            let start_pos = extra_obstacle_pos + dir.opposite().offset();
+
+            if contains_loop(map, extra_obstacle_pos, Some((start_pos, dir))) {
+                loop_obstacles.push(extra_obstacle_pos);
+
+                recorder.record(|| {
+                    draw_map(
+                        map,
+                        visited_positions.keys().map(|(pos, _)| *pos),
+                        Some((map.player_pos, map.player_dir)),
+                    )
+                    .overlay(loop_obstacles.iter().filter_map(|pos| pos.to_pos()), |_| {
+                        Cell::new('O').fg(Color::BrightRed)
+                    })
+                    .with_caption(format!(
+                        "{} obstacles make the guard walk in a loop",
+                        loop_obstacles.len()
+                    ))
+                });
+
+                continue;
+            }
+        }
+    }
+
+    loop_obstacles
+}
+
+// TODO: Test if this is actually faster lol
+pub fn part_two_smart(map: &Map) -> usize {
+    // Idea: We turn the player around at the start and have it run until it runs into a position a forward run would run into.
+    // If there is no such position we cannot get a loop, otherwise place an obstacle such that the player turns onto our backwards path
+
+    let mut num_loops = 0;
+
+    // Do a normal run
+    let mut visited_positions = HashSet::new();
+    let mut rock_locations = HashSet::new();
+
+    visited_positions.insert((map.player_pos, map.player_dir));
+
+    let mut player_pos = map.player_pos;
+    let mut player_dir = map.player_dir;
+    while let Some(pos) = {
+        let go_offset = player_dir.offset();
+        let next_pos_if_straight = player_pos + go_offset;
+
+        map.get(next_pos_if_straight)
+    } {
+        let go_offset = player_dir.offset();
+
+        let next_pos_if_straight = player_pos + go_offset;
+
+        match pos {
+            MapPos::Empty => {
+                assert!(visited_positions.contains(&(player_pos, player_dir)));
+
+                // The next position is empty, what if is wasn't?
+                // Only check each position ONCE, and never where the guard is right now
+                if next_pos_if_straight != map.player_pos
+                    && rock_locations.insert(next_pos_if_straight)
+                {
+                    if test_if_rock_here_means_loop(map, &visited_positions, player_pos, player_dir)
+                    {
+                        num_loops += 1;
+
+                        assert!(map.get(next_pos_if_straight).is_some());
+
+                        debug_assert!(contains_loop(map, next_pos_if_straight, None));
+                    } else {
+                        debug_assert!(!contains_loop(map, next_pos_if_straight, None));
+                    }
+                }
+
+                player_pos = next_pos_if_straight;
+            }
+            MapPos::Blocked => {
+                player_dir = player_dir.turn_right();
+            }
+        }
+
+        // We moved, check if we reached a pos we already had!
+        if !visited_positions.insert((player_pos, player_dir)) {
+            // We were here once and looked in the same direction.
+            // This means this is a loop!
+            unreachable!("Reaching this code means the starting input contains a loop!");
+        }
+    }
+
+    num_loops
+}
+
+fn test_if_rock_here_means_loop(
+    map: &Map,
+    visited_positions: &HashSet<(Vec2, Dir)>,
+    current_pos: Vec2,
+    current_dir: Dir,
+) -> bool {
+    let mut own_visited_pos = HashSet::new();
+
+    let rock_pos = current_pos + current_dir.offset();
+
+    own_visited_pos.insert((current_pos, current_dir));
+
+    let mut player_pos = current_pos;
+    let mut player_dir = current_dir;
+
+    while let Some(pos) = {
+        let go_offset = player_dir.offset();
+        let go_to_pos = player_pos + go_offset;
+
+        map.get(go_to_pos)
+    } {
+        let go_offset = player_dir.offset();
+
+        let next_pos_if_straight = player_pos + go_offset;
+
+        match pos {
+            MapPos::Empty => {
+                if next_pos_if_straight == rock_pos {
+                    // Act as if there was a rock there
+                    player_dir = player_dir.turn_right();
+                } else {
+                    // Act normally
+                    player_pos = next_pos_if_straight;
+                }
+            }
+            MapPos::Blocked => {
+                player_dir = player_dir.turn_right();
+            }
+        }
+
+        // We moved, check if we reached a pos we already had!
+        if visited_positions.contains(&(player_pos, player_dir)) {
+            // We were here once and looked in the same direction.
+            // This means this is a loop with the main!
+
+            return true;
+        }
+        if !own_visited_pos.insert((player_pos, player_dir)) {
+            // We were here once and looked in the same direction.
+            // This means this is a loop with iself!
+
+            return true;
+        }
+    }
+
+    false
+}
+
+fn contains_loop(map: &Map, extra_obstacle_pos: Vec2, custom_start: Option<(Vec2, Dir)>) -> bool {
+    let mut visited_positions = HashSet::new();
+
+    visited_positions.insert((map.player_pos, map.player_dir));
+
+    let (mut player_pos, mut player_dir) = if let Some((pos, dir)) = custom_start {
+        (pos, dir)
+    } else {
+        (map.player_pos, map.player_dir)
+    };
+
+    while let Some(pos) = {
+        let go_offset = player_dir.offset();
+        let go_to_pos = player_pos + go_offset;
+
+        if go_to_pos == extra_obstacle_pos {
+            Some(&MapPos::Blocked)
+        } else {
+            map.get(go_to_pos)
+        }
+    } {
+        let go_offset = player_dir.offset();
+        match pos {
+            MapPos::Empty => {
+                player_pos += go_offset;
+                // We moved, check if we reached a pos we already had!
+                if !visited_positions.insert((player_pos, player_dir)) {
+                    // We were here once and looked in the same direction.
+                    // This means this is a loop!
+                    return true;
+                }
+            }
+            MapPos::Blocked => {
+                player_dir = player_dir.turn_right();
+                // Boxed in guards only turn, so this has to be checked here too
+                if !visited_positions.insert((player_pos, player_dir)) {
+                    return true;
+                }
+            }
+        }
+    }
+
+    // We left the map, no loop
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+    use grid::Vec2;
+    use proptest::{
+        prelude::{prop, Strategy},
+        prop_assert_eq, proptest,
+    };
+
+    use crate::{
+        contains_loop, generate, parse, part_two_simple_bruteforce, part_two_smart,
+        part_two_smart_bruteforce, Day, Map,
+    };
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 41, 6);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 30);
+    }
+
+    // Small labs with the guard anywhere, facing any way, who eventually leaves the lab
+    fn lab(max_len: usize) -> impl Strategy<Value = Map> {
+        ((1..max_len), (1..max_len))
+            .prop_flat_map(|(width, height)| {
+                (
+                    prop::collection::vec(prop::bool::weighted(0.2), width * height),
+                    (0..width, 0..height),
+                    prop::sample::select(vec!['^', '>', 'v', '<']),
+                )
+                    .prop_map(move |(obstacles, (guard_x, guard_y), guard)| {
+                        let mut input = String::new();
+                        for y in 0..height {
+                            for x in 0..width {
+                                input.push(if (x, y) == (guard_x, guard_y) {
+                                    guard
+                                } else if obstacles[y * width + x] {
+                                    '#'
+                                } else {
+                                    '.'
+                                });
+                            }
+                            input.push('\n');
+                        }
+
+                        parse(&input).unwrap()
+                    })
+            })
+            .prop_filter("the guard has to leave the lab", |map| {
+                !contains_loop(map, Vec2::new(-1, -1), None)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_part_two_strategies_agree(map in lab(12)) {
+            let expected = part_two_simple_bruteforce(&map);
+
+            prop_assert_eq!(part_two_smart_bruteforce(&map), expected);
+            prop_assert_eq!(part_two_smart(&map), expected);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// `size` equations of 2 to 12 numbers. The test value comes from applying random operators
+// (including concatenation), and is sometimes nudged off so it can't be reached at all.
+// Equations are only kept if every combination of operators fits in a u64.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    let mut equations = 0;
+    while equations < size {
+        let len = rng.gen_range(2..=12);
+        let values: Vec<u64> = (0..len)
+            .map(|_| {
+                let digits = rng.gen_range(1..=3);
+                rng.gen_range(1..10_u64.pow(digits))
+            })
+            .collect();
+
+        if max_result(&values).is_none() {
+            continue;
+        }
+
+        let mut goal = values[0];
+        for value in &values[1..] {
+            goal = match rng.gen_range(0..3) {
+                0 => goal + value,
+                1 => goal * value,
+                _ => concat(goal, *value).expect("the maximum fits, so this does too"),
+            };
+        }
+
+        if rng.gen_bool(0.3) {
+            goal += 1;
+        }
+
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        input.push_str(&format!("{goal}: {}\n", values.join(" ")));
+        equations += 1;
+    }
+
+    input
+}
+
+// The largest test value any combination of operators gives, if it fits in a u64
+fn max_result(values: &[u64]) -> Option<u64> {
+    values[1..].iter().try_fold(values[0], |acc, value| {
+        let results = [
+            acc.checked_add(*value),
+            acc.checked_mul(*value),
+            concat(acc, *value),
+        ];
+
+        results
+            .into_iter()
+            .try_fold(0, |max, result| Some(max.max(result?)))
+    })
+}
+
+fn concat(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(10_u64.pow(b.ilog10() + 1))?.checked_add(b)
+}
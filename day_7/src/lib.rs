@@ -1,19 +1,23 @@
-use std::{
-    cmp::{max, min},
-    fs::File,
-    io::Read,
-};
+mod generate;
+
+use common::{parse_at, Answer, NoParams, ParseError, Solution};
+use std::cmp::{max, min};
+
+pub use generate::generate;
 
 #[derive(Debug)]
-struct Data {
+pub struct Data {
     entries: Vec<Entry>,
 }
 
 impl Data {
-    fn from_str(input: &str) -> Self {
-        Self {
-            entries: input.lines().map(Entry::from_str).collect(),
-        }
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            entries: input
+                .lines()
+                .map(|line| Entry::from_str(input, line))
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
@@ -24,33 +28,52 @@ struct Entry {
 }
 
 impl Entry {
-    fn from_str(input: &str) -> Self {
-        let (goal, rest) = input.split_once(':').expect("Input malformed (missing :)");
-
-        Self {
-            goal: str::parse(goal).expect("could not parse goal"),
-            values: rest
-                .split_whitespace()
-                .map(|v| str::parse(v).expect("Could not parse value"))
-                .collect(),
+    // `line` is a slice of `input`, which is only needed to locate errors
+    fn from_str(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (goal, rest) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(input, line, "expected ':' after the test value"))?;
+
+        let values: Vec<u64> = rest
+            .split_whitespace()
+            .map(|v| parse_at(input, v))
+            .collect::<Result<_, _>>()?;
+
+        if values.is_empty() {
+            return Err(ParseError::at(input, rest, "expected at least one value"));
         }
+
+        Ok(Self {
+            goal: parse_at(input, goal)?,
+            values,
+        })
     }
 }
 
-fn main() {
-    let mut input = String::new();
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
 
-    File::open("./input.txt")
-        .expect("Could not open File")
-        .read_to_string(&mut input)
-        .expect("Could not read File");
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
 
-    let data = Data::from_str(&input);
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
 
-    part_two(&data);
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
 }
 
-fn part_one(data: &Data) {
+pub fn part_one(data: &Data) -> u64 {
     let mut sum = 0;
 
     for entry in &data.entries {
@@ -59,10 +82,10 @@ fn part_one(data: &Data) {
         }
     }
 
-    dbg!(sum);
+    sum
 }
 
-fn part_two(data: &Data) {
+pub fn part_two(data: &Data) -> u64 {
     let mut sum = 0;
 
     for entry in &data.entries {
@@ -71,7 +94,7 @@ fn part_two(data: &Data) {
         }
     }
 
-    dbg!(sum);
+    sum
 }
 
 fn is_possible_mul_add(goal: u64, values: &[u64]) -> bool {
@@ -170,3 +193,25 @@ fn deconcat(a: u64, b: u64) -> Option<u64> {
         None
     }
 }
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 3749, 11387);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 50);
+    }
+}
@@ -0,0 +1,28 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// A `size` x `size` map with a handful of antennas of each frequency
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    const FREQUENCIES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+    let mut map = vec![vec!['.'; size]; size];
+    let num_frequencies = (size / 2).clamp(1, FREQUENCIES.len());
+
+    for frequency in FREQUENCIES.choose_multiple(&mut rng, num_frequencies) {
+        for _ in 0..rng.gen_range(2..=4) {
+            if size > 0 {
+                map[rng.gen_range(0..size)][rng.gen_range(0..size)] = *frequency as char;
+            }
+        }
+    }
+
+    let mut input = String::new();
+    for row in map {
+        input.extend(row);
+        input.push('\n');
+    }
+
+    input
+}
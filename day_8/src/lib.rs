@@ -0,0 +1,224 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use grid::{Grid, Pos, Vec2};
+use log::{debug, trace};
+use render::{Animate, Animation, Cell, Color, Exploration, Explore, Frame, Recorder};
+use std::collections::HashSet;
+
+pub use generate::generate;
+
+#[derive(Debug)]
+pub struct Data {
+    map: Grid<Tile>,
+    antennas: Vec<Pos>,
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut antennas = vec![];
+
+        let map = Grid::parse(input, |pos, c| match c {
+            '.' => Ok(Tile::Empty),
+            c if c.is_ascii_alphanumeric() => {
+                antennas.push(pos);
+                Ok(Tile::Antenna(c))
+            }
+            c => Err(ParseError::new(
+                pos.y + 1,
+                pos.x + 1,
+                c,
+                "expected '.' or an antenna (a letter or digit)",
+            )),
+        })?;
+
+        Ok(Self { map, antennas })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Empty,
+    Antenna(char),
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+// Every pair of antennas with the same frequency adds its antinodes
+impl Animate for Day {
+    fn animate(data: &Data, part: u8, animation: &mut Animation) {
+        match part {
+            1 => find_antinodes(data, animation),
+            _ => find_resonant_antinodes(data, animation),
+        };
+    }
+}
+
+// The antinodes of both parts as overlays
+impl Explore for Day {
+    fn explore(data: &Data) -> Exploration<'_> {
+        let antinodes = find_antinodes(data, &mut ());
+        let resonant_antinodes = find_resonant_antinodes(data, &mut ());
+
+        Exploration::new(draw_map(data, &HashSet::new(), &[]).with_caption(""))
+            .with_overlay("antinodes", Color::Red, antinodes.clone())
+            .with_overlay(
+                "resonant antinodes",
+                Color::Magenta,
+                resonant_antinodes.clone(),
+            )
+            .with_inspector(move |pos| {
+                let mut text = match data.map[pos] {
+                    Tile::Empty => "empty".to_string(),
+                    Tile::Antenna(frequency) => {
+                        let same_frequency = data
+                            .antennas
+                            .iter()
+                            .filter(|antenna| data.map[**antenna] == data.map[pos])
+                            .count();
+                        format!("antenna {frequency}, one of {same_frequency} with this frequency")
+                    }
+                };
+
+                if antinodes.contains(&pos) {
+                    text += ", antinode";
+                }
+                if resonant_antinodes.contains(&pos) {
+                    text += ", resonant antinode";
+                }
+
+                text
+            })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    find_antinodes(data, &mut ()).len()
+}
+
+fn find_antinodes(data: &Data, recorder: &mut impl Recorder) -> HashSet<Pos> {
+    let mut antinodes = HashSet::new();
+
+    for start_antenna in &data.antennas {
+        for end_antenna in &data.antennas {
+            if start_antenna == end_antenna {
+                continue;
+            }
+
+            if data.map[*start_antenna] != data.map[*end_antenna] {
+                trace!(
+                    "frequencies {:?} and {:?} differ",
+                    data.map[*start_antenna],
+                    data.map[*end_antenna]
+                );
+                continue;
+            }
+
+            let final_pos = Vec2::from(*end_antenna) + start_antenna.offset_to(*end_antenna);
+
+            trace!("antinode at {final_pos:?}");
+
+            if let Some(final_pos) = final_pos.to_pos().filter(|pos| data.map.contains(*pos)) {
+                antinodes.insert(final_pos);
+            }
+
+            recorder.record(|| draw_map(data, &antinodes, &[*start_antenna, *end_antenna]));
+        }
+    }
+
+    debug!("antinodes:\n{}", draw_map(data, &antinodes, &[]).plain());
+
+    antinodes
+}
+
+// The antennas and the antinodes found so far, with the pair of antennas that is looked at
+fn draw_map(data: &Data, antinodes: &HashSet<Pos>, pair: &[Pos]) -> Frame {
+    Frame::new(&data.map, |_, tile| match tile {
+        Tile::Empty => Cell::new('.').fg(Color::BrightBlack),
+        Tile::Antenna(c) => Cell::new(*c).fg(Color::Cyan),
+    })
+    .overlay(antinodes.iter().copied(), |_| Cell::new('#').fg(Color::Red))
+    .overlay(pair.iter().copied(), |cell| cell.bg(Color::Blue))
+    .with_caption(format!("{} antinodes", antinodes.len()))
+}
+
+pub fn part_two(data: &Data) -> usize {
+    find_resonant_antinodes(data, &mut ()).len()
+}
+
+// Part two: the antinodes go on in a line, and the antennas are antinodes too
+fn find_resonant_antinodes(data: &Data, recorder: &mut impl Recorder) -> HashSet<Pos> {
+    let mut antinodes = HashSet::new();
+
+    for start_antenna in &data.antennas {
+        for end_antenna in &data.antennas {
+            if start_antenna == end_antenna {
+                continue;
+            }
+
+            if data.map[*start_antenna] != data.map[*end_antenna] {
+                // the nodes do not have the same frequency
+                continue;
+            }
+
+            let offs = start_antenna.offset_to(*end_antenna);
+
+            let mut pos = Some(*end_antenna);
+
+            while let Some(current) = pos {
+                antinodes.insert(current);
+
+                pos = data.map.step(current, offs);
+            }
+
+            recorder.record(|| draw_map(data, &antinodes, &[*start_antenna, *end_antenna]));
+        }
+    }
+
+    debug!("antinodes:\n{}", draw_map(data, &antinodes, &[]).plain());
+
+    antinodes
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 14, 34);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 30);
+    }
+}
@@ -0,0 +1,18 @@
+// Random inputs in the shape of the real puzzle, for stress tests and benchmarks
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// A disk map of `size` files, each 1 to 9 blocks long, with 0 to 9 free blocks in between
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut input = String::new();
+    for file in 0..size {
+        if file > 0 {
+            input.push(char::from_digit(rng.gen_range(0..=9), 10).unwrap());
+        }
+        input.push(char::from_digit(rng.gen_range(1..=9), 10).unwrap());
+    }
+    input.push('\n');
+
+    input
+}
@@ -0,0 +1,199 @@
+mod generate;
+
+use common::{Answer, NoParams, ParseError, Solution};
+use log::trace;
+use std::iter::repeat_n;
+
+pub use generate::generate;
+
+#[derive(Debug, Clone)]
+pub struct Data {
+    disk: Vec<Sector>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Sector {
+    Empty,
+    Full(usize),
+}
+
+impl Data {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let line = input
+            .lines()
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or_else(|| ParseError::at_end(input, "expected a disk map"))?;
+
+        let mut disk = vec![];
+        for (i, c) in line.chars().enumerate() {
+            let len = c
+                .to_digit(10)
+                .ok_or_else(|| ParseError::new(1, i + 1, c, "expected a digit"))?
+                as usize;
+
+            if i % 2 == 0 {
+                // This is a file
+                disk.extend(repeat_n(Sector::Full(i / 2), len));
+            } else {
+                disk.extend(repeat_n(Sector::Empty, len));
+            }
+        }
+
+        Ok(Self { disk })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Data;
+    type Params = NoParams;
+
+    fn parse(input: &str, _params: NoParams) -> Result<Data, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(data: &Data) -> Answer {
+        part_one(data).into()
+    }
+
+    fn part_two(data: &Data) -> Answer {
+        part_two(data).into()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Data, ParseError> {
+    Data::from_str(input)
+}
+
+pub fn part_two(data: &Data) -> usize {
+    let data = &mut data.clone();
+
+    for right_idx in (0..data.disk.len()).rev() {
+        if let Some(Sector::Full(_)) = data.disk.get(right_idx) {
+        } else {
+            // This is not a file
+            continue;
+        }
+
+        if right_idx > 0 && data.disk[right_idx - 1] == data.disk[right_idx] {
+            // We are not at the leftmost position for this file
+            continue;
+        }
+
+        let file_len = data.disk[right_idx..]
+            .iter()
+            .take_while(|s| Some(s) == data.disk.get(right_idx).as_ref())
+            .count();
+
+        assert!(file_len > 0);
+
+        for left_idx in 0..data.disk.len() {
+            if left_idx >= right_idx {
+                break;
+            }
+
+            // Check if there is enough space for the file
+            if data.disk[left_idx..]
+                .iter()
+                .take(file_len)
+                .all(|s| *s == Sector::Empty)
+            {
+                // Copy the file over
+                for offs in 0..file_len {
+                    data.disk.swap(left_idx + offs, right_idx + offs);
+                }
+
+                trace!("moved the file at {right_idx} to {left_idx}");
+                break;
+            }
+        }
+    }
+
+    // Calc checksum
+    get_checksum(data)
+}
+
+pub fn part_one(data: &Data) -> usize {
+    let data = &mut data.clone();
+
+    // Start at the end. Files of length 0 can leave nothing on the disk at all.
+    let mut left_idx = 0;
+    let Some(mut right_idx) = data.disk.len().checked_sub(1) else {
+        return 0;
+    };
+
+    loop {
+        while left_idx < right_idx && matches!(data.disk[left_idx], Sector::Full(_)) {
+            left_idx += 1;
+        }
+
+        while left_idx < right_idx && Sector::Empty == data.disk[right_idx] {
+            right_idx -= 1;
+        }
+
+        if left_idx >= right_idx {
+            break;
+        }
+
+        data.disk.swap(left_idx, right_idx);
+    }
+
+    // Calc checksum
+    get_checksum(data)
+}
+
+fn get_checksum(data: &Data) -> usize {
+    let mut checksum = 0;
+
+    for (block_idx, block) in data.disk.iter().enumerate() {
+        if let Sector::Full(file_id) = block {
+            checksum += block_idx * file_id;
+        }
+    }
+
+    checksum
+}
+
+#[cfg(test)]
+mod test {
+    use common::testing::{check_example, check_generated_inputs, check_personal_answers};
+
+    use crate::{generate, parse, part_one, part_two, Day};
+
+    #[test]
+    fn test_example() {
+        check_example::<Day>(include_str!("../example.txt"), 1928, 2858);
+    }
+
+    #[test]
+    fn test_personal_answers() {
+        check_personal_answers::<Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        check_generated_inputs::<Day>(generate, 200);
+    }
+
+    #[test]
+    fn test_small_disks() {
+        for (input, checksum) in [
+            ("1", 0),
+            ("9", 0),
+            ("0", 0),
+            ("00000", 0),
+            ("12", 0),
+            ("1012", 1),
+            ("112", 3),
+        ] {
+            let data = parse(input).unwrap();
+            assert_eq!(part_one(&data), checksum, "{input:?}");
+            part_two(&data);
+        }
+
+        assert!(parse("\n").is_err());
+        assert!(parse("").is_err());
+    }
+}
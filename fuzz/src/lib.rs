@@ -0,0 +1,20 @@
+use common::Solution;
+
+// The contract of every parser: whatever the input, it returns the parsed data or a
+// `ParseError`, but never panics. The parsers take a &str, so bytes that aren't valid
+// UTF-8 never reach them.
+pub fn check_parse<S: Solution>(data: &[u8]) {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = S::parse(input, S::Params::default());
+    }
+}
+
+// For the days whose part one is quick on any input: whatever parses has to get through
+// part one as well, like the generated inputs in the tests
+pub fn check_part_one<S: Solution>(data: &[u8]) {
+    if let Ok(input) = std::str::from_utf8(data) {
+        if let Ok(parsed) = S::parse(input, S::Params::default()) {
+            S::part_one(&parsed);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::Vec2;
+
+// One of the 8 directions on a grid, in clockwise order starting at the top left.
+// Up is towards the first line of the input, so it has a negative dy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    UL,
+    U,
+    UR,
+    R,
+    DR,
+    D,
+    DL,
+    L,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 8] = [
+        Dir::UL,
+        Dir::U,
+        Dir::UR,
+        Dir::R,
+        Dir::DR,
+        Dir::D,
+        Dir::DL,
+        Dir::L,
+    ];
+
+    // Clockwise, starting at the top
+    pub const CARDINALS: [Dir; 4] = [Dir::U, Dir::R, Dir::D, Dir::L];
+
+    // Clockwise, starting at the top left
+    pub const DIAGONALS: [Dir; 4] = [Dir::UL, Dir::UR, Dir::DR, Dir::DL];
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn cardinals() -> impl Iterator<Item = Self> {
+        Self::CARDINALS.into_iter()
+    }
+
+    pub fn diagonals() -> impl Iterator<Item = Self> {
+        Self::DIAGONALS.into_iter()
+    }
+
+    pub const fn dx(self) -> isize {
+        match self {
+            Dir::UL | Dir::L | Dir::DL => -1,
+            Dir::U | Dir::D => 0,
+            Dir::UR | Dir::R | Dir::DR => 1,
+        }
+    }
+
+    pub const fn dy(self) -> isize {
+        match self {
+            Dir::UL | Dir::U | Dir::UR => -1,
+            Dir::L | Dir::R => 0,
+            Dir::DL | Dir::D | Dir::DR => 1,
+        }
+    }
+
+    pub const fn offset(self) -> Vec2 {
+        Vec2::new(self.dx(), self.dy())
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        self.offset().manhattan_len() == 1
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !self.is_cardinal()
+    }
+
+    // Clockwise by `eighths` * 45°
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn turn_right_45(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_left_45(self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    // The arrows used in the puzzle inputs: ^ > v <
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir::U),
+            '>' => Some(Dir::R),
+            'v' => Some(Dir::D),
+            '<' => Some(Dir::L),
+            _ => None,
+        }
+    }
+
+    // None for diagonals, which have no arrow
+    pub fn arrow(self) -> Option<char> {
+        match self {
+            Dir::U => Some('^'),
+            Dir::R => Some('>'),
+            Dir::D => Some('v'),
+            Dir::L => Some('<'),
+            Dir::UL | Dir::UR | Dir::DR | Dir::DL => None,
+        }
+    }
+}
+
+impl From<Dir> for Vec2 {
+    fn from(dir: Dir) -> Self {
+        dir.offset()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Dir, Vec2};
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Dir::U.offset(), Vec2::new(0, -1));
+        assert_eq!(Dir::R.offset(), Vec2::new(1, 0));
+        assert_eq!(Dir::DL.offset(), Vec2::new(-1, 1));
+        assert!(Dir::all().all(|dir| dir.offset() == -dir.opposite().offset()));
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Dir::U.turn_right(), Dir::R);
+        assert_eq!(Dir::U.turn_left(), Dir::L);
+        assert_eq!(Dir::L.turn_right_45(), Dir::UL);
+        assert_eq!(Dir::UL.turn_left_45(), Dir::L);
+        assert!(Dir::cardinals().all(|dir| dir.turn_right().is_cardinal()));
+        assert!(Dir::diagonals().all(|dir| dir.turn_right_45().is_cardinal()));
+    }
+
+    #[test]
+    fn test_arrows() {
+        for dir in Dir::cardinals() {
+            assert_eq!(Dir::from_arrow(dir.arrow().unwrap()), Some(dir));
+        }
+        assert_eq!(Dir::from_arrow('x'), None);
+        assert_eq!(Dir::UR.arrow(), None);
+    }
+}
@@ -0,0 +1,244 @@
+mod dir;
+mod pos;
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use common::ParseError;
+
+pub use dir::Dir;
+pub use pos::{Pos, Vec2};
+
+// A rectangular grid, stored row by row in a single Vec
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos::new(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Every line of the input is a row, every char a cell. All rows need to be equally wide.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(Pos, char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (y, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(Pos::new(x, y), c)?);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::new(
+                        y + 1,
+                        width.min(line_width) + 1,
+                        line,
+                        format!("expected a row of {width} cells, found {line_width}"),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos).then_some(pos.y * self.width + pos.x)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    // Like `get`, but for positions that might be left of or above the grid
+    pub fn get_signed(&self, pos: Vec2) -> Option<&T> {
+        self.get(pos.to_pos()?)
+    }
+
+    // `pos + offset`, if that is still inside the grid
+    pub fn step(&self, pos: Pos, offset: Vec2) -> Option<Pos> {
+        pos.checked_add(offset).filter(|pos| self.contains(*pos))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, but an empty grid has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(pos, _)| pos)
+    }
+
+    // The up to 4 neighbours sharing an edge with `pos`
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::cardinals().filter_map(move |dir| self.step(pos, dir.offset()))
+    }
+
+    // The up to 8 neighbours sharing an edge or a corner with `pos`
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::all().filter_map(move |dir| self.step(pos, dir.offset()))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Pos, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(pos, value)| f(pos, value)).collect(),
+        }
+    }
+
+    // Turns the grid back into text, one line per row
+    pub fn render(&self, mut f: impl FnMut(Pos, &T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+
+        for (pos, value) in self.iter() {
+            out.push(f(pos, value));
+
+            if pos.x == self.width - 1 {
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{pos:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+// Grids of chars (or anything else that displays as a single char) print as they were parsed
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Grid, Pos, Vec2};
+
+    #[test]
+    fn test_parse_and_render() {
+        let input = "#.#\n..#\n";
+        let grid = Grid::parse(input, |_, c| Ok(c == '#')).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[Pos::new(2, 1)]);
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get_signed(Vec2::new(-1, 0)), None);
+        assert_eq!(grid.render(|_, wall| if *wall { '#' } else { '.' }), input);
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let err = Grid::parse("...\n..\n", |_, c| Ok(c)).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, ());
+
+        assert_eq!(grid.neighbours4(Pos::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(),
+            vec![Pos::new(1, 0), Pos::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Pos::new(2, 2)).count(), 3);
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::VecDeque;
+
+use crate::Frame;
+
+// Simulations take one of these and hand it every state worth looking at. The frame is only
+// drawn if anybody watches: solving a part passes `&mut ()`, which never calls `frame`.
+pub trait Recorder {
+    fn record(&mut self, frame: impl FnOnce() -> Frame);
+}
+
+impl Recorder for () {
+    fn record(&mut self, _frame: impl FnOnce() -> Frame) {}
+}
+
+// The recorded frames of one or more simulations, in order. Some simulations run for
+// thousands of steps on big maps, so with a limit only the last `limit` frames are kept.
+#[derive(Debug, Clone, Default)]
+pub struct Animation {
+    frames: VecDeque<Frame>,
+    limit: Option<usize>,
+    dropped: usize,
+}
+
+impl Animation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Frame> {
+        self.frames.get(index)
+    }
+
+    pub fn frames(&self) -> impl Iterator<Item = &Frame> {
+        self.frames.iter()
+    }
+
+    // How many frames from the start were thrown away because of the limit
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+impl Recorder for Animation {
+    fn record(&mut self, frame: impl FnOnce() -> Frame) {
+        if self.limit == Some(0) {
+            self.dropped += 1;
+            return;
+        }
+
+        if Some(self.frames.len()) == self.limit {
+            self.frames.pop_front();
+            self.dropped += 1;
+        }
+
+        self.frames.push_back(frame());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Animation, Cell, Frame, Recorder};
+
+    fn frame(ch: char) -> Frame {
+        Frame::from_fn(1, 1, |_| Cell::new(ch))
+    }
+
+    #[test]
+    fn test_limit() {
+        let mut animation = Animation::with_limit(2);
+
+        for ch in ['a', 'b', 'c'] {
+            animation.record(|| frame(ch));
+        }
+
+        let kept: String = animation.frames().map(Frame::plain).collect();
+        assert_eq!(kept, "b\nc\n");
+        assert_eq!(animation.dropped(), 1);
+
+        // Nobody watches, so nothing gets drawn
+        ().record(|| unreachable!());
+    }
+}
@@ -0,0 +1,379 @@
+use std::{
+    collections::HashSet,
+    io::{self, IsTerminal, Write},
+    ops::Range,
+};
+
+use colored::Color;
+use grid::Pos;
+
+use crate::{
+    terminal::{self, Key, Keys, RawMode},
+    Cell, Frame,
+};
+
+const HELP: &str = "arrows or h/j/k/l: move (H/J/K/L: 10 tiles), n/b: next/previous step, \
+                    </>: first/last step, 1-9: overlays, q: quit";
+
+// The lines below the map: the caption of the frame, the cell under the cursor, the overlays
+// and the help
+const STATUS_LINES: usize = 4;
+
+// Everything the explorer shows of a day: its map at every step of the simulation, overlays
+// that can be switched on and off, and what there is to know about every cell
+pub struct Exploration<'a> {
+    num_steps: usize,
+    draw_step: Box<dyn Fn(usize) -> Frame + 'a>,
+    overlays: Vec<Overlay>,
+    inspect: Box<dyn Fn(Pos) -> String + 'a>,
+}
+
+struct Overlay {
+    name: String,
+    color: Color,
+    positions: HashSet<Pos>,
+}
+
+impl<'a> Exploration<'a> {
+    // A map that doesn't change
+    pub fn new(map: Frame) -> Self {
+        Self::with_steps(1, move |_| map.clone())
+    }
+
+    // The states of a simulation, drawn by `draw_step` when they are looked at. Keeping all of
+    // them around like an `Animation` does would take far too much memory on a big map.
+    pub fn with_steps(num_steps: usize, draw_step: impl Fn(usize) -> Frame + 'a) -> Self {
+        assert!(num_steps > 0, "there has to be something to explore");
+
+        Self {
+            num_steps,
+            draw_step: Box::new(draw_step),
+            overlays: Vec::new(),
+            inspect: Box::new(|_| String::new()),
+        }
+    }
+
+    // Cells that can be highlighted in `color`, like the path through a maze. The overlays
+    // are switched on and off with the keys 1 to 9, in the order they are added here.
+    pub fn with_overlay(
+        mut self,
+        name: impl Into<String>,
+        color: Color,
+        positions: impl IntoIterator<Item = Pos>,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            name: name.into(),
+            color,
+            positions: positions.into_iter().collect(),
+        });
+        self
+    }
+
+    // What is shown about the cell under the cursor, like its height or distance to the start
+    pub fn with_inspector(mut self, inspect: impl Fn(Pos) -> String + 'a) -> Self {
+        self.inspect = Box::new(inspect);
+        self
+    }
+
+    pub fn num_steps(&self) -> usize {
+        self.num_steps
+    }
+
+    pub fn overlay_names(&self) -> impl Iterator<Item = &str> {
+        self.overlays.iter().map(|overlay| overlay.name.as_str())
+    }
+
+    pub fn inspect(&self, pos: Pos) -> String {
+        (self.inspect)(pos)
+    }
+
+    // The map at `step` with the overlays that are switched on in `shown`
+    pub fn draw(&self, step: usize, shown: &[bool]) -> Frame {
+        let frame = (self.draw_step)(step.min(self.num_steps - 1));
+
+        self.overlays
+            .iter()
+            .zip(shown)
+            .filter(|(_, shown)| **shown)
+            .fold(frame, |frame, (overlay, _)| {
+                frame.overlay(overlay.positions.iter().copied(), |cell| {
+                    cell.bg(overlay.color)
+                })
+            })
+    }
+}
+
+// What can be done with a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Move { dx: isize, dy: isize },
+    Step(isize),
+    FirstStep,
+    LastStep,
+    // Of the overlay with this index
+    Toggle(usize),
+    Quit,
+}
+
+impl Action {
+    fn from_key(key: Key) -> Option<Self> {
+        let action = match key {
+            Key::Up | Key::Char('k') => Action::Move { dx: 0, dy: -1 },
+            Key::Down | Key::Char('j') => Action::Move { dx: 0, dy: 1 },
+            Key::Left | Key::Char('h') => Action::Move { dx: -1, dy: 0 },
+            Key::Right | Key::Char('l') => Action::Move { dx: 1, dy: 0 },
+            Key::Char('K') => Action::Move { dx: 0, dy: -10 },
+            Key::Char('J') => Action::Move { dx: 0, dy: 10 },
+            Key::Char('H') => Action::Move { dx: -10, dy: 0 },
+            Key::Char('L') => Action::Move { dx: 10, dy: 0 },
+            Key::Char('n' | ' ') => Action::Step(1),
+            Key::Char('b') => Action::Step(-1),
+            Key::Char('<') => Action::FirstStep,
+            Key::Char('>') => Action::LastStep,
+            Key::Char(digit @ '1'..='9') => Action::Toggle(usize::from(digit as u8 - b'1')),
+            // Ctrl-C and Ctrl-D
+            Key::Char('q' | '\x03' | '\x04') => Action::Quit,
+            Key::Char(_) => return None,
+        };
+
+        Some(action)
+    }
+}
+
+// Where the cursor is and what is shown, independent of any terminal
+#[derive(Debug, Clone, PartialEq)]
+struct View {
+    cursor: Pos,
+    step: usize,
+    shown: Vec<bool>,
+}
+
+impl View {
+    fn new(num_overlays: usize) -> Self {
+        Self {
+            cursor: Pos::new(0, 0),
+            step: 0,
+            shown: vec![false; num_overlays],
+        }
+    }
+
+    fn apply(&mut self, action: Action, width: usize, height: usize, num_steps: usize) {
+        let last_step = num_steps.saturating_sub(1);
+
+        match action {
+            Action::Move { dx, dy } => {
+                self.cursor = Pos::new(
+                    self.cursor
+                        .x
+                        .saturating_add_signed(dx)
+                        .min(width.saturating_sub(1)),
+                    self.cursor
+                        .y
+                        .saturating_add_signed(dy)
+                        .min(height.saturating_sub(1)),
+                );
+            }
+            Action::Step(steps) => {
+                self.step = self.step.saturating_add_signed(steps).min(last_step);
+            }
+            Action::FirstStep => self.step = 0,
+            Action::LastStep => self.step = last_step,
+            Action::Toggle(index) => {
+                if let Some(shown) = self.shown.get_mut(index) {
+                    *shown = !*shown;
+                }
+            }
+            Action::Quit => {}
+        }
+    }
+}
+
+// The part of a row or column of length `len` that fits into `space`, keeping the cursor in
+// the middle where possible
+fn window(cursor: usize, len: usize, space: usize) -> Range<usize> {
+    if len <= space {
+        return 0..len;
+    }
+
+    let start = cursor.saturating_sub(space / 2).min(len - space);
+    start..start + space
+}
+
+// Shows the exploration in the terminal until q is pressed. The keys are read one by one, or
+// line by line if stdin isn't a terminal.
+pub fn explore(exploration: &Exploration) -> io::Result<()> {
+    let _raw_mode = RawMode::enable()?;
+    let mut out = io::stdout().lock();
+
+    // Like a pager, on a screen of its own that goes away again at the end
+    let fullscreen = io::stdout().is_terminal();
+    if fullscreen {
+        write!(out, "\x1b[?1049h\x1b[?25l")?;
+    }
+
+    let result = run(&mut out, exploration);
+
+    if fullscreen {
+        write!(out, "\x1b[?25h\x1b[?1049l")?;
+        out.flush()?;
+    }
+
+    result
+}
+
+fn run(out: &mut impl Write, exploration: &Exploration) -> io::Result<()> {
+    let mut view = View::new(exploration.overlays.len());
+    let mut keys = Keys::new(io::stdin().lock());
+
+    loop {
+        let frame = exploration.draw(view.step, &view.shown);
+        let (width, height) = (frame.cells().width(), frame.cells().height());
+
+        // A step may have a smaller map than the one before
+        view.apply(Action::Move { dx: 0, dy: 0 }, width, height, 0);
+
+        draw(out, exploration, &frame, &view, terminal::size())?;
+
+        // Nobody can press anything anymore once stdin is closed
+        let Some(action) = keys.by_ref().find_map(Action::from_key) else {
+            return Ok(());
+        };
+
+        if action == Action::Quit {
+            return Ok(());
+        }
+
+        view.apply(action, width, height, exploration.num_steps());
+    }
+}
+
+fn draw(
+    out: &mut impl Write,
+    exploration: &Exploration,
+    frame: &Frame,
+    view: &View,
+    size: Option<(usize, usize)>,
+) -> io::Result<()> {
+    let cells = frame.cells();
+    let (columns, rows) = size.unwrap_or((usize::MAX, usize::MAX));
+    let xs = window(view.cursor.x, cells.width(), columns);
+    let ys = window(
+        view.cursor.y,
+        cells.height(),
+        rows.saturating_sub(STATUS_LINES).max(1),
+    );
+
+    // Clear the screen and start at the top left
+    write!(out, "\x1b[2J\x1b[H")?;
+
+    for y in ys {
+        for x in xs.clone() {
+            let pos = Pos::new(x, y);
+            let cell = cells[pos];
+
+            if pos == view.cursor {
+                write!(
+                    out,
+                    "{}",
+                    Cell::new(cell.ch).fg(Color::Black).bg(Color::White)
+                )?;
+            } else {
+                write!(out, "{cell}")?;
+            }
+        }
+        writeln!(out)?;
+    }
+
+    writeln!(out, "{}", frame.caption())?;
+
+    let Pos { x, y } = view.cursor;
+    writeln!(
+        out,
+        "step {}/{}, ({x}, {y}): {}",
+        view.step + 1,
+        exploration.num_steps(),
+        exploration.inspect(view.cursor)
+    )?;
+
+    let overlays: Vec<String> = exploration
+        .overlay_names()
+        .zip(&view.shown)
+        .enumerate()
+        .map(|(index, (name, shown))| {
+            format!(
+                "{}: {name} ({})",
+                index + 1,
+                if *shown { "on" } else { "off" }
+            )
+        })
+        .collect();
+    writeln!(out, "{}", overlays.join(", "))?;
+
+    write!(out, "{HELP}")?;
+
+    out.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use colored::Color;
+    use grid::Pos;
+
+    use super::{window, Action, Exploration, View};
+    use crate::{terminal::Key, Cell, Frame};
+
+    #[test]
+    fn test_overlays() {
+        let exploration = Exploration::with_steps(2, |step| {
+            Frame::from_fn(3, 1, |_| Cell::new(if step == 0 { '.' } else { '#' }))
+        })
+        .with_overlay("left", Color::Red, [Pos::new(0, 0)])
+        .with_overlay("right", Color::Blue, [Pos::new(2, 0)])
+        .with_inspector(|pos| format!("x is {}", pos.x));
+
+        let frame = exploration.draw(1, &[false, true]);
+        assert_eq!(frame.plain(), "###\n");
+        assert_eq!(frame.cells()[Pos::new(0, 0)].bg, None);
+        assert_eq!(frame.cells()[Pos::new(2, 0)].bg, Some(Color::Blue));
+
+        assert_eq!(exploration.inspect(Pos::new(1, 0)), "x is 1");
+        assert_eq!(
+            exploration.overlay_names().collect::<Vec<_>>(),
+            ["left", "right"]
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let mut view = View::new(2);
+
+        let mut press = |key| {
+            let action = Action::from_key(key).expect("all keys in this test do something");
+            view.apply(action, 20, 5, 3);
+            view.clone()
+        };
+
+        assert_eq!(press(Key::Char('L')).cursor, Pos::new(10, 0));
+        assert_eq!(press(Key::Char('J')).cursor, Pos::new(10, 4));
+        assert_eq!(press(Key::Left).cursor, Pos::new(9, 4));
+        assert_eq!(press(Key::Char('H')).cursor, Pos::new(0, 4));
+
+        assert_eq!(press(Key::Char('>')).step, 2);
+        assert_eq!(press(Key::Char('n')).step, 2);
+        assert_eq!(press(Key::Char('b')).step, 1);
+
+        assert_eq!(press(Key::Char('2')).shown, [false, true]);
+        assert_eq!(press(Key::Char('9')).shown, [false, true]);
+
+        assert_eq!(Action::from_key(Key::Char('q')), Some(Action::Quit));
+        assert_eq!(Action::from_key(Key::Char('x')), None);
+    }
+
+    #[test]
+    fn test_window() {
+        assert_eq!(window(3, 10, 20), 0..10);
+        assert_eq!(window(2, 100, 10), 0..10);
+        assert_eq!(window(50, 100, 10), 45..55);
+        assert_eq!(window(99, 100, 10), 90..100);
+    }
+}
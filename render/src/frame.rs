@@ -0,0 +1,164 @@
+use std::fmt::{self, Display};
+
+use colored::{Color, Colorize};
+use grid::{Grid, Pos};
+
+// A single character on the screen, optionally coloured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+}
+
+impl Cell {
+    pub fn new(ch: char) -> Self {
+        Self {
+            ch,
+            fg: None,
+            bg: None,
+        }
+    }
+
+    pub fn fg(self, color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    pub fn bg(self, color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(ch: char) -> Self {
+        Self::new(ch)
+    }
+}
+
+// The character with its colours, unless colours are turned off (see the `colored` crate)
+impl Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = self.ch.to_string().normal();
+
+        if let Some(fg) = self.fg {
+            text = text.color(fg);
+        }
+        if let Some(bg) = self.bg {
+            text = text.on_color(bg);
+        }
+
+        write!(f, "{text}")
+    }
+}
+
+// One picture of a simulation: a grid of cells with a line of text below it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    caption: String,
+}
+
+impl Frame {
+    // Draws every tile of `grid`, usually by matching on the tile enum of the day
+    pub fn new<T>(grid: &Grid<T>, f: impl FnMut(Pos, &T) -> Cell) -> Self {
+        Self {
+            cells: grid.map(f),
+            caption: String::new(),
+        }
+    }
+
+    // For days that don't keep their state in a grid
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Pos) -> Cell) -> Self {
+        Self {
+            cells: Grid::from_fn(width, height, f),
+            caption: String::new(),
+        }
+    }
+
+    // Draws over the cells at `positions`, like the path of the guard or the antinodes.
+    // `f` gets the cell that is there already, so an overlay can keep the character and only
+    // change the colour. Positions outside of the frame are ignored.
+    pub fn overlay(
+        mut self,
+        positions: impl IntoIterator<Item = Pos>,
+        mut f: impl FnMut(Cell) -> Cell,
+    ) -> Self {
+        for pos in positions {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                *cell = f(*cell);
+            }
+        }
+
+        self
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    // The frame without any colours
+    pub fn plain(&self) -> String {
+        let mut out = self.cells.render(|_, cell| cell.ch);
+
+        if !self.caption.is_empty() {
+            out.push_str(&self.caption);
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+// Prints the frame with colours, unless colours are turned off (see the `colored` crate)
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        if !self.caption.is_empty() {
+            writeln!(f, "{}", self.caption)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use colored::Color;
+    use grid::{Grid, Pos};
+
+    use crate::{Cell, Frame};
+
+    #[test]
+    fn test_overlay() {
+        let walls = Grid::parse("#..\n.#.\n", |_, c| Ok(c == '#')).unwrap();
+
+        let frame = Frame::new(&walls, |_, wall| Cell::new(if *wall { '#' } else { '.' }))
+            .overlay([Pos::new(1, 0), Pos::new(7, 7)], |_| Cell::new('X'))
+            .overlay([Pos::new(1, 1)], |cell| cell.fg(Color::Red))
+            .with_caption("step 1");
+
+        assert_eq!(frame.plain(), "#X.\n.#.\nstep 1\n");
+        assert_eq!(frame.cells()[Pos::new(1, 1)], Cell::new('#').fg(Color::Red));
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use colored::Color;
+use grid::{Grid, Pos};
+
+use crate::{Animation, Cell, Frame};
+
+pub type Rgb = [u8; 3];
+
+// Cells without any colour, like the walls of most days
+const DEFAULT_COLOR: Rgb = [192, 192, 192];
+
+// An RGB picture of a grid, ready to be saved as PPM, PNG or a frame of a GIF
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    // Every tile becomes a `scale` x `scale` square in the colour the palette picks for it,
+    // usually by matching on the tile enum of the day
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        scale: usize,
+        mut palette: impl FnMut(Pos, &T) -> Rgb,
+    ) -> Self {
+        let scale = scale.max(1);
+        let colors = grid.map(|pos, tile| palette(pos, tile));
+
+        let width = grid.width() * scale;
+        let height = grid.height() * scale;
+
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos::new(x / scale, y / scale)))
+            .map(|pos| colors[pos])
+            .collect();
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    // The colours of a frame, see `cell_color`. The characters and the caption are lost.
+    pub fn from_frame(frame: &Frame, scale: usize) -> Self {
+        Self::from_grid(frame.cells(), scale, |_, cell| cell_color(cell))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    pub fn to_png(&self) -> io::Result<Vec<u8>> {
+        let (Ok(width), Ok(height)) = (u32::try_from(self.width), u32::try_from(self.height))
+        else {
+            return Err(too_big("a PNG", self.width, self.height));
+        };
+
+        let mut out = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(self.pixels.as_flattened())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)?;
+
+        Ok(out)
+    }
+
+    // Saves the image as PNG or PPM, depending on the extension of `path`
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match extension(path).as_deref() {
+            Some("png") => self.to_png()?,
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is not a .png or .ppm file", path.display()),
+                ))
+            }
+        };
+
+        std::fs::write(path, bytes)
+    }
+}
+
+// The colour a cell gets in an image: the background if it has one, otherwise the text colour
+pub fn cell_color(cell: &Cell) -> Rgb {
+    cell.bg.or(cell.fg).map(rgb).unwrap_or(DEFAULT_COLOR)
+}
+
+// The usual colours of a terminal
+pub fn rgb(color: Color) -> Rgb {
+    match color {
+        Color::Black => [0, 0, 0],
+        Color::Red => [205, 49, 49],
+        Color::Green => [13, 188, 121],
+        Color::Yellow => [229, 229, 16],
+        Color::Blue => [36, 114, 200],
+        Color::Magenta => [188, 63, 188],
+        Color::Cyan => [17, 168, 205],
+        Color::White => [229, 229, 229],
+        Color::BrightBlack => [102, 102, 102],
+        Color::BrightRed => [241, 76, 76],
+        Color::BrightGreen => [35, 209, 139],
+        Color::BrightYellow => [245, 245, 67],
+        Color::BrightBlue => [59, 142, 234],
+        Color::BrightMagenta => [214, 112, 214],
+        Color::BrightCyan => [41, 184, 219],
+        Color::BrightWhite => [255, 255, 255],
+        Color::TrueColor { r, g, b } => [r, g, b],
+    }
+}
+
+impl Animation {
+    // Saves all frames as an animated GIF if `path` ends in .gif, otherwise only the last
+    // frame as PNG or PPM
+    pub fn save(&self, path: &Path, scale: usize, fps: f64) -> io::Result<()> {
+        if extension(path).as_deref() != Some("gif") {
+            return match self.frames().last() {
+                Some(frame) => Image::from_frame(frame, scale).save(path),
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "there is no frame to save",
+                )),
+            };
+        }
+
+        let Some(first) = self.frames().next() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "there is no frame to save",
+            ));
+        };
+
+        let mut gif = GifEncoder::new(
+            BufWriter::new(File::create(path)?),
+            first.cells().width() * scale.max(1),
+            first.cells().height() * scale.max(1),
+            fps,
+        )?;
+
+        for frame in self.frames() {
+            gif.add_frame(&Image::from_frame(frame, scale))?;
+        }
+
+        gif.finish()?.flush()
+    }
+}
+
+// Writes an animated GIF that loops forever, one frame at a time. All frames have to be the
+// same size. Every frame has a palette of its own, with the exact colours if there are at
+// most 256 of them, and the closest 256 the gif crate can find otherwise.
+pub struct GifEncoder<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    // Hundredths of a second per frame
+    delay: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    pub fn new(out: W, width: usize, height: usize, fps: f64) -> io::Result<Self> {
+        let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(too_big("a GIF", width, height));
+        };
+
+        let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        // Most viewers play anything faster than 50 fps a lot slower
+        let delay = (100.0 / fps).round().clamp(2.0, u16::MAX as f64) as u16;
+
+        Ok(Self {
+            encoder,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn add_frame(&mut self, image: &Image) -> io::Result<()> {
+        if (image.width, image.height) != (usize::from(self.width), usize::from(self.height)) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "all frames of a GIF need to be {}x{}, not {}x{}",
+                    self.width, self.height, image.width, image.height
+                ),
+            ));
+        }
+
+        let mut frame = match exact_palette(image) {
+            Some((indices, palette)) => {
+                gif::Frame::from_palette_pixels(self.width, self.height, indices, palette, None)
+            }
+            // Speed 10 is what `Frame::from_rgb` uses, a good trade between time and quality
+            None => {
+                gif::Frame::from_rgb_speed(self.width, self.height, image.pixels.as_flattened(), 10)
+            }
+        };
+        frame.delay = self.delay;
+
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+
+    pub fn finish(self) -> io::Result<W> {
+        self.encoder.into_inner()
+    }
+}
+
+// The palette index of every pixel and the palette, if there are at most 256 colours
+fn exact_palette(image: &Image) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut palette: HashMap<Rgb, u8> = HashMap::new();
+
+    let indices = image
+        .pixels
+        .iter()
+        .map(|pixel| {
+            let next = u8::try_from(palette.len()).ok();
+            match palette.get(pixel) {
+                Some(index) => Some(*index),
+                None => {
+                    let index = next?;
+                    palette.insert(*pixel, index);
+                    Some(index)
+                }
+            }
+        })
+        .collect::<Option<Vec<u8>>>()?;
+
+    let mut colors = vec![[0; 3]; palette.len()];
+    for (color, index) in palette {
+        colors[usize::from(index)] = color;
+    }
+
+    Some((indices, colors.concat()))
+}
+
+fn too_big(what: &str, width: usize, height: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("{width}x{height} is too big for {what}"),
+    )
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+}
+
+#[cfg(test)]
+mod test {
+    use colored::Color;
+    use grid::Grid;
+
+    use super::{GifEncoder, Image};
+    use crate::{Cell, Frame};
+
+    #[test]
+    fn test_from_frame() {
+        let frame = Frame::from_fn(2, 1, |pos| match pos.x {
+            0 => Cell::new('#'),
+            _ => Cell::new('.').fg(Color::Red).bg(Color::Blue),
+        });
+
+        let image = Image::from_frame(&frame, 2);
+
+        assert_eq!((image.width(), image.height()), (4, 2));
+        assert_eq!(image.pixel(1, 1), [192, 192, 192]);
+        assert_eq!(image.pixel(2, 0), [36, 114, 200]);
+        assert_eq!(&image.to_ppm()[..11], b"P6\n4 2\n255\n");
+    }
+
+    #[test]
+    fn test_png() {
+        let image = Image::from_grid(&Grid::new(3, 2, ()), 1, |pos, _| {
+            [pos.x as u8, pos.y as u8, 7]
+        });
+        let png = image.to_png().unwrap();
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(info.color_type, png::ColorType::Rgb);
+        assert_eq!(&pixels[..info.buffer_size()], image.pixels.as_flattened());
+    }
+
+    // Decodes all frames as RGB
+    fn decode_gif(bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(bytes).unwrap();
+
+        let mut frames = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push(
+                frame
+                    .buffer
+                    .chunks(4)
+                    .flat_map(|rgba| &rgba[..3])
+                    .copied()
+                    .collect(),
+            );
+        }
+        frames
+    }
+
+    #[test]
+    fn test_gif() {
+        let images = [[1, 2, 3], [200, 100, 0]]
+            .map(|color| Image::from_grid(&Grid::new(4, 3, ()), 1, |_, _| color));
+
+        let mut gif = GifEncoder::new(Vec::new(), 4, 3, 10.0).unwrap();
+        for image in &images {
+            gif.add_frame(image).unwrap();
+        }
+        let frames = decode_gif(&gif.finish().unwrap());
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], images[0].pixels.as_flattened());
+        assert_eq!(frames[1], images[1].pixels.as_flattened());
+
+        let mut gif = GifEncoder::new(Vec::new(), 4, 3, 10.0).unwrap();
+        assert!(gif
+            .add_frame(&Image::from_grid(&Grid::new(3, 3, ()), 1, |_, _| [0; 3]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_gif_with_many_colors() {
+        let image = Image::from_grid(&Grid::new(20, 20, ()), 1, |pos, _| {
+            [pos.x as u8 * 12, pos.y as u8 * 12, 128]
+        });
+
+        let mut gif = GifEncoder::new(Vec::new(), 20, 20, 10.0).unwrap();
+        gif.add_frame(&image).unwrap();
+        let frames = decode_gif(&gif.finish().unwrap());
+
+        // 400 colours don't fit into a palette, but they come out close on average
+        assert_eq!(frames[0].len(), 20 * 20 * 3);
+        let error = frames[0]
+            .iter()
+            .zip(image.pixels.as_flattened())
+            .map(|(decoded, original)| u32::from(decoded.abs_diff(*original)))
+            .sum::<u32>()
+            / (20 * 20 * 3);
+        assert!(error < 16, "off by {error} on average");
+    }
+}
@@ -0,0 +1,26 @@
+mod animation;
+mod explorer;
+mod frame;
+mod image;
+mod player;
+mod terminal;
+
+use common::Solution;
+
+pub use animation::{Animation, Recorder};
+pub use colored::Color;
+pub use explorer::{explore, Exploration};
+pub use frame::{Cell, Frame};
+pub use image::{cell_color, rgb, GifEncoder, Image, Rgb};
+pub use player::{Command, Player};
+
+// Days with a simulation worth watching implement this next to `Solution`.
+// `part` is 1 or 2, and every state of that part's simulation ends up in `animation`.
+pub trait Animate: Solution {
+    fn animate(data: &Self::Input, part: u8, animation: &mut Animation);
+}
+
+// Days with a map worth looking at up close implement this next to `Solution`, see `explore`
+pub trait Explore: Solution {
+    fn explore(data: &Self::Input) -> Exploration<'_>;
+}
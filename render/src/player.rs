@@ -0,0 +1,313 @@
+use std::{
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::Animation;
+
+const HELP: &str =
+    "Enter: step, b: back, p: pause, g N: go to frame N, +/-: faster/slower, q: quit";
+
+// What can be typed (followed by Enter) while an animation plays
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    // Nothing, or "n"
+    Forward,
+    // "b"
+    Back,
+    // "p"
+    TogglePause,
+    // "g 42", the frames are numbered from 1, counting the ones the animation didn't keep
+    Seek(usize),
+    // "+"
+    Faster,
+    // "-"
+    Slower,
+    // "q"
+    Quit,
+}
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+
+        let command = match (words.next(), words.next()) {
+            (None | Some("n"), None) => Command::Forward,
+            (Some("b"), None) => Command::Back,
+            (Some("p"), None) => Command::TogglePause,
+            (Some("g"), Some(frame)) => match frame.parse::<usize>() {
+                Ok(frame @ 1..) => Command::Seek(frame),
+                _ => return Err(format!("`{frame}` is not a frame number")),
+            },
+            (Some("+"), None) => Command::Faster,
+            (Some("-"), None) => Command::Slower,
+            (Some("q"), None) => Command::Quit,
+            _ => return Err(format!("unknown command `{}`", line.trim())),
+        };
+
+        if words.next().is_some() {
+            return Err(format!("unknown command `{}`", line.trim()));
+        }
+
+        Ok(command)
+    }
+}
+
+// Where the playback is, independent of any terminal. `frame` is the index into the frames
+// that were kept, the first `dropped` frames of the simulation come before those.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Playback {
+    frame: usize,
+    dropped: usize,
+    paused: bool,
+    fps: f64,
+}
+
+impl Playback {
+    // An error if the command asks for a frame that wasn't kept, it goes as close as it can
+    fn apply(&mut self, command: Command, num_frames: usize) -> Result<(), String> {
+        let last = num_frames.saturating_sub(1);
+
+        match command {
+            Command::Forward => {
+                self.frame = (self.frame + 1).min(last);
+                self.paused = true;
+            }
+            Command::Back => {
+                self.frame = self.frame.saturating_sub(1);
+                self.paused = true;
+            }
+            Command::TogglePause => self.paused = !self.paused,
+            Command::Seek(frame) => {
+                self.frame = frame.saturating_sub(self.dropped + 1).min(last);
+
+                if frame <= self.dropped {
+                    return Err(format!(
+                        "frame {frame} was not kept, the first one left is {}",
+                        self.dropped + 1
+                    ));
+                }
+            }
+            Command::Faster => self.fps = (self.fps * 2.0).min(1000.0),
+            Command::Slower => self.fps = (self.fps / 2.0).max(0.1),
+            Command::Quit => {}
+        }
+
+        Ok(())
+    }
+
+    // Counted from 1 like the frames of `Command::Seek`
+    fn number(&self) -> usize {
+        self.dropped + self.frame + 1
+    }
+
+    // Called once per frame while playing
+    fn tick(&mut self, num_frames: usize) {
+        if !self.paused && self.frame + 1 < num_frames {
+            self.frame += 1;
+        }
+    }
+
+    fn at_end(&self, num_frames: usize) -> bool {
+        self.frame + 1 >= num_frames
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+}
+
+// Plays an animation in the terminal, `fps` frames per second. The commands (see `Command`)
+// are read line by line from stdin, so they need Enter. Once the last frame is reached the
+// player waits for more commands, unless stdin is closed, in which case it just stops there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Player {
+    fps: f64,
+    paused: bool,
+}
+
+impl Player {
+    pub fn new(fps: f64) -> Self {
+        Self { fps, paused: false }
+    }
+
+    // Start on the first frame and wait for commands instead of playing right away
+    pub fn paused(self, paused: bool) -> Self {
+        Self { paused, ..self }
+    }
+
+    pub fn play(&self, animation: &Animation) -> io::Result<()> {
+        let num_frames = animation.len();
+        if num_frames == 0 {
+            return Ok(());
+        }
+
+        let mut playback = Playback {
+            frame: 0,
+            dropped: animation.dropped(),
+            paused: self.paused,
+            fps: self.fps,
+        };
+
+        let mut commands = Some(read_commands());
+        let mut message = None;
+        let mut out = io::stdout().lock();
+
+        loop {
+            draw(&mut out, animation, &playback, message.take())?;
+
+            let waiting = playback.paused || playback.at_end(num_frames);
+
+            let received = match &commands {
+                Some(commands) if waiting => {
+                    commands.recv().map_err(|_| RecvTimeoutError::Disconnected)
+                }
+                Some(commands) => commands.recv_timeout(playback.interval()),
+                None if waiting => return Ok(()),
+                None => {
+                    thread::sleep(playback.interval());
+                    Err(RecvTimeoutError::Timeout)
+                }
+            };
+
+            match received {
+                Ok(line) => match Command::parse(&line) {
+                    Ok(Command::Quit) => return Ok(()),
+                    Ok(command) => message = playback.apply(command, num_frames).err(),
+                    Err(err) => message = Some(err),
+                },
+                Err(RecvTimeoutError::Timeout) => playback.tick(num_frames),
+                Err(RecvTimeoutError::Disconnected) => {
+                    // Nobody can type anything, so play to the end
+                    commands = None;
+                    playback.paused = false;
+                }
+            }
+        }
+    }
+}
+
+// Lines typed on stdin, read on another thread so the animation keeps playing meanwhile
+fn read_commands() -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+fn draw(
+    out: &mut impl Write,
+    animation: &Animation,
+    playback: &Playback,
+    message: Option<String>,
+) -> io::Result<()> {
+    let frame = animation
+        .get(playback.frame)
+        .expect("the playback stays inside of the animation");
+
+    // Clear the screen and start at the top left
+    write!(out, "\x1b[2J\x1b[H{frame}")?;
+
+    write!(
+        out,
+        "frame {}/{} at {} fps, {}",
+        playback.number(),
+        animation.dropped() + animation.len(),
+        playback.fps,
+        if playback.paused { "paused" } else { "playing" }
+    )?;
+    if animation.dropped() > 0 {
+        write!(out, " (frames 1 to {} were not kept)", animation.dropped())?;
+    }
+    writeln!(out)?;
+
+    match message {
+        Some(message) => writeln!(out, "{message}")?,
+        None => writeln!(out, "{HELP}")?,
+    }
+
+    out.flush()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Command, Playback};
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(Command::parse(""), Ok(Command::Forward));
+        assert_eq!(Command::parse(" g 12 "), Ok(Command::Seek(12)));
+        assert_eq!(Command::parse("-"), Ok(Command::Slower));
+        assert!(Command::parse("g 0").is_err());
+        assert!(Command::parse("p p").is_err());
+        assert!(Command::parse("jump").is_err());
+    }
+
+    #[test]
+    fn test_playback() {
+        let mut playback = Playback {
+            frame: 0,
+            dropped: 0,
+            paused: false,
+            fps: 10.0,
+        };
+
+        playback.tick(3);
+        playback.tick(3);
+        playback.tick(3);
+        assert_eq!(playback.frame, 2);
+        assert!(playback.at_end(3));
+
+        playback.apply(Command::Back, 3).unwrap();
+        assert_eq!((playback.frame, playback.paused), (1, true));
+
+        // Paused playback only moves on command
+        playback.tick(3);
+        playback.apply(Command::Seek(10), 3).unwrap();
+        assert_eq!(playback.frame, 2);
+
+        playback.apply(Command::Seek(1), 3).unwrap();
+        playback.apply(Command::TogglePause, 3).unwrap();
+        playback.apply(Command::Faster, 3).unwrap();
+        playback.tick(3);
+        assert_eq!(
+            (playback.frame, playback.paused, playback.fps),
+            (1, false, 20.0)
+        );
+    }
+
+    #[test]
+    fn test_dropped_frames() {
+        // Frames 1 to 100 were dropped, 101 to 103 are left
+        let mut playback = Playback {
+            frame: 0,
+            dropped: 100,
+            paused: true,
+            fps: 10.0,
+        };
+        assert_eq!(playback.number(), 101);
+
+        playback.apply(Command::Seek(102), 3).unwrap();
+        assert_eq!((playback.frame, playback.number()), (1, 102));
+
+        assert_eq!(
+            playback.apply(Command::Seek(5), 3),
+            Err("frame 5 was not kept, the first one left is 101".to_string())
+        );
+        assert_eq!(playback.number(), 101);
+
+        playback.apply(Command::Seek(500), 3).unwrap();
+        assert_eq!(playback.number(), 103);
+    }
+}
@@ -0,0 +1,196 @@
+use std::io::{self, BufReader, Bytes, Read};
+#[cfg(unix)]
+use std::{
+    panic,
+    sync::{Mutex, Once, PoisonError},
+};
+
+// A key press, as far as the explorer cares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Key {
+    Char(char),
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+// Turns the bytes read from stdin into keys. The arrow keys arrive as escape sequences, other
+// sequences and anything else that isn't plain ASCII are skipped.
+pub(crate) struct Keys<R> {
+    bytes: Bytes<BufReader<R>>,
+    // A byte after Esc that turned out not to belong to a sequence
+    pending: Option<u8>,
+}
+
+impl<R: Read> Keys<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            bytes: BufReader::new(reader).bytes(),
+            pending: None,
+        }
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        self.pending.take().or_else(|| self.bytes.next()?.ok())
+    }
+}
+
+impl<R: Read> Iterator for Keys<R> {
+    type Item = Key;
+
+    fn next(&mut self) -> Option<Key> {
+        loop {
+            match self.next_byte()? {
+                // ESC [ A to ESC [ D
+                0x1b => {
+                    let byte = self.next_byte()?;
+                    if byte != b'[' {
+                        // Esc pressed on its own, the key after it counts
+                        self.pending = Some(byte);
+                        continue;
+                    }
+
+                    // Parameters like the 5 of Page Up (ESC [ 5 ~) come before the final byte
+                    let last = loop {
+                        match self.next_byte()? {
+                            byte @ 0x40..=0x7e => break byte,
+                            _ => continue,
+                        }
+                    };
+
+                    match last {
+                        b'A' => return Some(Key::Up),
+                        b'B' => return Some(Key::Down),
+                        b'C' => return Some(Key::Right),
+                        b'D' => return Some(Key::Left),
+                        _ => continue,
+                    }
+                }
+                byte if byte.is_ascii() => return Some(Key::Char(char::from(byte))),
+                _ => continue,
+            }
+        }
+    }
+}
+
+// The settings of the terminal from before `RawMode::enable`, while raw mode is on
+#[cfg(unix)]
+static SAVED: Mutex<Option<libc::termios>> = Mutex::new(None);
+
+// Puts the saved settings back, if there are any. Runs on drop and in a panic hook, which comes
+// before the panic message is printed and also works if panics abort.
+#[cfg(unix)]
+fn restore() {
+    let saved = SAVED.lock().unwrap_or_else(PoisonError::into_inner).take();
+
+    if let Some(saved) = saved {
+        // SAFETY: `saved` came from tcgetattr
+        unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &saved) };
+    }
+}
+
+// While this lives, every key press can be read from stdin right away, without Enter and
+// without showing up on the screen. Ctrl-C arrives as a key too, so whoever reads the keys
+// gets to clean up. If stdin isn't a terminal nothing changes, so keys can also be piped in.
+pub(crate) struct RawMode {
+    _private: (),
+}
+
+impl RawMode {
+    #[cfg(unix)]
+    pub(crate) fn enable() -> io::Result<Self> {
+        use std::io::IsTerminal;
+
+        static PANIC_HOOK: Once = Once::new();
+
+        if !io::stdin().is_terminal() {
+            return Ok(Self { _private: () });
+        }
+
+        // SAFETY: termios is plain data that tcgetattr fills in
+        let mut termios: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(libc::STDIN_FILENO, &mut termios) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let saved = termios;
+
+        termios.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG);
+        termios.c_cc[libc::VMIN] = 1;
+        termios.c_cc[libc::VTIME] = 0;
+
+        PANIC_HOOK.call_once(|| {
+            let previous = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                restore();
+                previous(info);
+            }));
+        });
+
+        *SAVED.lock().unwrap_or_else(PoisonError::into_inner) = Some(saved);
+
+        // SAFETY: `termios` is the current settings with a few flags changed
+        if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &termios) } != 0 {
+            let err = io::Error::last_os_error();
+            restore();
+            return Err(err);
+        }
+
+        Ok(Self { _private: () })
+    }
+
+    // Other systems need Enter after the keys
+    #[cfg(not(unix))]
+    pub(crate) fn enable() -> io::Result<Self> {
+        Ok(Self { _private: () })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        restore();
+    }
+}
+
+// The columns and rows of the terminal, None if stdout isn't one
+pub(crate) fn size() -> Option<(usize, usize)> {
+    #[cfg(unix)]
+    {
+        // SAFETY: winsize is plain data that the ioctl fills in
+        let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+        let found = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+
+        (found && size.ws_col > 0 && size.ws_row > 0)
+            .then(|| (usize::from(size.ws_col), usize::from(size.ws_row)))
+    }
+
+    #[cfg(not(unix))]
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Key, Keys};
+
+    #[test]
+    fn test_keys() {
+        let keys: Vec<Key> = Keys::new("j\x1b[A\x1b[Dq\u{e9}\x1bx1".as_bytes()).collect();
+
+        assert_eq!(
+            keys,
+            [
+                Key::Char('j'),
+                Key::Up,
+                Key::Left,
+                Key::Char('q'),
+                Key::Char('x'),
+                Key::Char('1'),
+            ]
+        );
+
+        // Esc on its own, then Page Up, which isn't a key of the explorer
+        let keys: Vec<Key> = Keys::new("\x1bq\x1b[5~\x1b\x1b[Bn".as_bytes()).collect();
+        assert_eq!(keys, [Key::Char('q'), Key::Down, Key::Char('n')]);
+    }
+}